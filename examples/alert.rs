@@ -0,0 +1,10 @@
+use cliux::Alert;
+
+fn main() {
+    Alert::new("error", "Disk full").print();
+
+    Alert::new("warning", "Low memory")
+        .details("Only 120 MB remain available. Close other applications before continuing.")
+        .width(50)
+        .print();
+}
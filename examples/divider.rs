@@ -2,4 +2,5 @@ use cliux::Divider;
 
 fn main() {
     Divider::new(20).print();
+    Divider::new(20).shaded().print();
 }
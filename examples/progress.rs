@@ -0,0 +1,12 @@
+use cliux::ProgressBar;
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let mut bar = ProgressBar::new(40).width(30).color("green");
+    for _ in 0..40 {
+        thread::sleep(Duration::from_millis(50));
+        bar.inc(1);
+    }
+    bar.finish();
+}
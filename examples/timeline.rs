@@ -0,0 +1,11 @@
+use cliux::Timeline;
+
+fn main() {
+    Timeline::new()
+        .event("Built", "12:01")
+        .event("Tested", "12:03")
+        .active("Deploying to production across all three regions", "12:05")
+        .pending("Verify", "")
+        .width(30)
+        .print();
+}
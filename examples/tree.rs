@@ -0,0 +1,14 @@
+use cliux::{Tree, TreeNode};
+
+fn main() {
+    Tree::new(
+        TreeNode::new("cliux")
+            .child(
+                TreeNode::new("src")
+                    .child(TreeNode::new("components").child(TreeNode::new("tree.rs")))
+                    .child(TreeNode::new("lib.rs")),
+            )
+            .child(TreeNode::new("Cargo.toml")),
+    )
+    .print();
+}
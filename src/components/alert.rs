@@ -0,0 +1,181 @@
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::components::note::{get_border, kind_preset};
+use crate::layout::{default_width, pad, wrap_text};
+use crate::style::paint;
+use crate::theme::theme;
+use ansi_term::Style;
+
+/// A compact status block that leads with a bracketed severity label.
+///
+/// The `Alert` struct renders a message such as `[ERROR] Disk full` inside a
+/// box whose borders and label take the color of the severity. It reuses the
+/// same severities as `Note` kinds, but leads with a label rather than an icon,
+/// and can carry an optional wrapped line of details.
+///
+/// # Examples
+///
+/// A single-line alert:
+/// ```
+/// use cliux::Alert;
+///
+/// Alert::new("error", "Disk full").print();
+/// ```
+///
+/// An alert with wrapped details:
+/// ```
+/// use cliux::Alert;
+///
+/// Alert::new("warning", "Low memory")
+///     .details("Only 120 MB remain available. Close other applications before continuing.")
+///     .width(50)
+///     .print();
+/// ```
+pub struct Alert {
+    severity: String,
+    message: String,
+    details: Option<String>,
+    style: String,
+    width: usize,
+}
+
+impl Alert {
+    /// Creates a new `Alert` with the given severity and message.
+    ///
+    /// The severity selects the color and boldness using the same presets as
+    /// `Note::kind` ("debug", "info", "tip", "warning", "error"). Unknown
+    /// severities are shown uncolored. By default, the alert has "rounded"
+    /// borders and a width of 50 characters (see `set_default_width`).
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - The severity name, shown uppercased in the label.
+    /// * `message` - The message displayed after the label.
+    ///
+    /// # Returns
+    ///
+    /// A new `Alert` instance.
+    pub fn new(severity: &str, message: impl Into<String>) -> Self {
+        Self {
+            severity: severity.to_string(),
+            message: message.into(),
+            details: None,
+            style: theme()
+                .border_style
+                .unwrap_or_else(|| "rounded".to_string()),
+            width: default_width().unwrap_or(50),
+        }
+    }
+
+    /// Adds a line of details below the message.
+    ///
+    /// This method consumes `self` and returns a new `Alert` instance,
+    /// allowing for method chaining. The details are wrapped to fit
+    /// within the alert's interior width.
+    ///
+    /// # Arguments
+    ///
+    /// * `details` - A string slice containing the additional details.
+    ///
+    /// # Returns
+    ///
+    /// The `Alert` instance with the details set.
+    pub fn details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Sets the border style for the alert.
+    ///
+    /// This method consumes `self` and returns a new `Alert` instance,
+    /// allowing for method chaining. Supports the same styles as `Note`:
+    /// `"rounded"` (default), `"square"`, and `"+"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - A string slice representing the desired border style.
+    ///
+    /// # Returns
+    ///
+    /// The `Alert` instance with the updated border style.
+    pub fn style(mut self, style: &str) -> Self {
+        self.style = style.to_string();
+        self
+    }
+
+    /// Sets the total width of the alert box.
+    ///
+    /// This method consumes `self` and returns a new `Alert` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The desired total width of the alert box in characters.
+    ///
+    /// # Returns
+    ///
+    /// The `Alert` instance with the updated width.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Renders the formatted alert into a `String`.
+    ///
+    /// The borders and severity label are painted in the severity's color,
+    /// while the message and details keep the terminal's default color.
+    /// Lines are separated by newlines, with no trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered alert.
+    pub fn render(&self) -> String {
+        let (tl, tr, bl, br, h, v) = get_border(&self.style);
+
+        let mut accent = Style::new();
+        let mut label_style = Style::new();
+        if let Some((_, color, bold, _)) = kind_preset(&self.severity) {
+            if let Some(colour) = parse_colour(&color) {
+                accent = accent.fg(colour);
+                label_style = label_style.fg(colour);
+            }
+            if bold {
+                label_style = label_style.bold();
+            }
+        }
+
+        // Subtract 2 for the vertical borders and two spaces for padding inside
+        let content_width = self.width.saturating_sub(4);
+        let horizontal = h.repeat(self.width.saturating_sub(2));
+        let left = paint(&accent, v);
+        let right = paint(&accent, v);
+
+        // Pad the plain text first so the escape codes don't count towards the width
+        let label = format!("[{}]", self.severity.to_uppercase());
+        let padded = pad(&format!("{} {}", label, self.message), content_width);
+        let rest = &padded[label.len()..];
+
+        let mut lines = vec![
+            paint(&accent, &format!("{}{}{}", tl, horizontal, tr)),
+            format!("{} {}{} {}", left, paint(&label_style, &label), rest, right),
+        ];
+        if let Some(ref details) = self.details {
+            for line in wrap_text(details, content_width) {
+                lines.push(format!("{} {} {}", left, pad(&line, content_width), right));
+            }
+        }
+        lines.push(paint(&accent, &format!("{}{}{}", bl, horizontal, br)));
+        lines.join("\n")
+    }
+
+    /// Prints the formatted alert to the console.
+    pub fn print(&self) {
+        Render::print(self)
+    }
+}
+
+impl Render for Alert {
+    fn render(&self) -> String {
+        Alert::render(self)
+    }
+}
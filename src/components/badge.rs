@@ -0,0 +1,151 @@
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::style::{color_enabled, paint};
+use ansi_term::Style;
+
+/// A pill-shaped status marker: short text on a solid colored background.
+///
+/// Unlike `Tag`, which wraps its text in brackets, a `Badge` pads its text
+/// with a space on each side and fills the whole block, padding included,
+/// with the background color, e.g. a green ` PASSED ` block in CI output.
+/// When color is disabled by the `ColorMode`, the badge falls back to
+/// bracketed plain text such as `[PASSED]`, so it still stands out.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::Badge;
+///
+/// Badge::new("PASSED").fg("black").bg("green").bold(true).print();
+///
+/// let badge = Badge::new("v1.2").inline();
+/// println!("Released {}", badge);
+/// ```
+pub struct Badge {
+    text: String,
+    fg: String,
+    bg: String,
+    bold: bool,
+}
+
+impl Badge {
+    /// Creates a new `Badge` instance with the given text.
+    ///
+    /// By default, the badge has white text on a blue background and is not bold.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The string slice to display inside the badge.
+    ///
+    /// # Returns
+    ///
+    /// A new `Badge` instance.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            fg: "white".to_string(),
+            bg: "blue".to_string(),
+            bold: false,
+        }
+    }
+
+    /// Sets the color of the badge's text.
+    ///
+    /// This method consumes `self` and returns a new `Badge` instance,
+    /// allowing for method chaining. Accepts the same colors as `Label::color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired text color.
+    ///
+    /// # Returns
+    ///
+    /// The `Badge` instance with the updated text color.
+    pub fn fg(mut self, color: &str) -> Self {
+        self.fg = color.to_string();
+        self
+    }
+
+    /// Sets the background color of the badge.
+    ///
+    /// This method consumes `self` and returns a new `Badge` instance,
+    /// allowing for method chaining. Accepts the same colors as `Label::color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired background color.
+    ///
+    /// # Returns
+    ///
+    /// The `Badge` instance with the updated background color.
+    pub fn bg(mut self, color: &str) -> Self {
+        self.bg = color.to_string();
+        self
+    }
+
+    /// Sets whether the badge's text should be bold.
+    ///
+    /// This method consumes `self` and returns a new `Badge` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `bold` - A boolean indicating whether the text should be bold (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Badge` instance with the updated bold setting.
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    /// Returns the formatted badge as a `String`, suitable for inline use.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the padded text painted with the badge's colors,
+    /// or the text in brackets when color is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```standalone_crate
+    /// use cliux::{Badge, ColorMode, set_color_mode};
+    ///
+    /// set_color_mode(ColorMode::Never);
+    /// assert_eq!(Badge::new("PASSED").bg("green").inline(), "[PASSED]");
+    ///
+    /// set_color_mode(ColorMode::Always);
+    /// assert_eq!(
+    ///     Badge::new("OK").fg("black").bg("green").inline(),
+    ///     "\x1b[42;30m OK \x1b[0m"
+    /// );
+    /// ```
+    pub fn inline(&self) -> String {
+        if !color_enabled() {
+            return format!("[{}]", self.text);
+        }
+        let mut style = Style::new();
+        if let Some(colour) = parse_colour(&self.fg) {
+            style = style.fg(colour);
+        }
+        if let Some(colour) = parse_colour(&self.bg) {
+            style = style.on(colour);
+        }
+        if self.bold {
+            style = style.bold();
+        }
+        paint(&style, &format!(" {} ", self.text))
+    }
+
+    /// Prints the badge to the console.
+    pub fn print(&self) {
+        Render::print(self)
+    }
+}
+
+impl Render for Badge {
+    fn render(&self) -> String {
+        self.inline()
+    }
+}
@@ -0,0 +1,131 @@
+use crate::components::Render;
+use crate::components::banner_font::{HEIGHT, glyph};
+use crate::components::label::parse_colour;
+use crate::style::{paint, unicode_enabled};
+use ansi_term::Style;
+
+/// Width of the blank columns drawn for characters the font doesn't cover.
+const UNKNOWN_WIDTH: usize = 3;
+
+/// A component for displaying oversized text, such as splash screens.
+///
+/// The `Banner` struct draws its text five rows tall using a built-in block
+/// font of `█` characters, covering A–Z (lowercase is drawn as uppercase),
+/// 0–9, space, and the punctuation marks `! ? . , - : ' /`. Characters
+/// outside the font are drawn as blank columns. When Unicode is disabled
+/// with `set_unicode(false)`, the font is drawn with `#` instead.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::Banner;
+///
+/// Banner::new("CLIUX").color("cyan").print();
+/// ```
+pub struct Banner {
+    text: String,
+    color: Option<String>,
+}
+
+impl Banner {
+    /// Creates a new `Banner` instance with the given text.
+    ///
+    /// Each line of `text` is drawn as its own five-row block, separated
+    /// by a blank line.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The string slice to display in large letters.
+    ///
+    /// # Returns
+    ///
+    /// A new `Banner` instance.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            color: None,
+        }
+    }
+
+    /// Sets the color of the banner.
+    ///
+    /// This method consumes `self` and returns a new `Banner` instance,
+    /// allowing for method chaining. Accepts the same colors as `Label::color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `Banner` instance with the updated color.
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Renders the banner into a `String`.
+    ///
+    /// Glyphs are separated by one blank column and trailing spaces are
+    /// trimmed from each row. Rows are separated by newlines, with no
+    /// trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered banner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Banner;
+    ///
+    /// assert_eq!(
+    ///     Banner::new("HI").render(),
+    ///     "█   █ ███\n\
+    ///      █   █  █\n\
+    ///      █████  █\n\
+    ///      █   █  █\n\
+    ///      █   █ ███"
+    /// );
+    /// ```
+    pub fn render(&self) -> String {
+        let fill = if unicode_enabled() { "█" } else { "#" };
+        let mut style = Style::new();
+        if let Some(colour) = self.color.as_deref().and_then(parse_colour) {
+            style = style.fg(colour);
+        }
+
+        let mut blocks = Vec::new();
+        for line in self.text.lines() {
+            let mut rows = vec![String::new(); HEIGHT];
+            for (i, c) in line.chars().enumerate() {
+                for (r, row) in rows.iter_mut().enumerate() {
+                    if i > 0 {
+                        row.push(' ');
+                    }
+                    match glyph(c) {
+                        Some(g) => row.push_str(&g[r].replace('#', fill)),
+                        None => row.push_str(&" ".repeat(UNKNOWN_WIDTH)),
+                    }
+                }
+            }
+            let rows: Vec<String> = rows
+                .iter()
+                .map(|row| paint(&style, row.trim_end()))
+                .collect();
+            blocks.push(rows.join("\n"));
+        }
+        blocks.join("\n\n")
+    }
+
+    /// Prints the banner to the console.
+    pub fn print(&self) {
+        Render::print(self)
+    }
+}
+
+impl Render for Banner {
+    fn render(&self) -> String {
+        Banner::render(self)
+    }
+}
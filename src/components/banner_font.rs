@@ -0,0 +1,338 @@
+//! The built-in block font used by `Banner`.
+
+/// The number of rows in every glyph.
+pub(crate) const HEIGHT: usize = 5;
+
+/// Glyphs for each supported character, sorted by character for lookup.
+///
+/// Each glyph is five rows of equal width, with `#` marking a filled cell.
+/// `Banner` swaps `#` for `█` when Unicode is enabled.
+#[rustfmt::skip]
+pub(crate) static GLYPHS: &[(char, [&str; HEIGHT])] = &[
+    (' ', [
+        "   ",
+        "   ",
+        "   ",
+        "   ",
+        "   ",
+    ]),
+    ('!', [
+        "#",
+        "#",
+        "#",
+        " ",
+        "#",
+    ]),
+    ('\'', [
+        "#",
+        "#",
+        " ",
+        " ",
+        " ",
+    ]),
+    (',', [
+        "  ",
+        "  ",
+        "  ",
+        " #",
+        "# ",
+    ]),
+    ('-', [
+        "   ",
+        "   ",
+        "###",
+        "   ",
+        "   ",
+    ]),
+    ('.', [
+        " ",
+        " ",
+        " ",
+        " ",
+        "#",
+    ]),
+    ('/', [
+        "    #",
+        "   # ",
+        "  #  ",
+        " #   ",
+        "#    ",
+    ]),
+    ('0', [
+        " ### ",
+        "#  ##",
+        "# # #",
+        "##  #",
+        " ### ",
+    ]),
+    ('1', [
+        " # ",
+        "## ",
+        " # ",
+        " # ",
+        "###",
+    ]),
+    ('2', [
+        " ### ",
+        "#   #",
+        "  ## ",
+        " #   ",
+        "#####",
+    ]),
+    ('3', [
+        "#### ",
+        "    #",
+        " ### ",
+        "    #",
+        "#### ",
+    ]),
+    ('4', [
+        "#   #",
+        "#   #",
+        "#####",
+        "    #",
+        "    #",
+    ]),
+    ('5', [
+        "#####",
+        "#    ",
+        "#### ",
+        "    #",
+        "#### ",
+    ]),
+    ('6', [
+        " ### ",
+        "#    ",
+        "#### ",
+        "#   #",
+        " ### ",
+    ]),
+    ('7', [
+        "#####",
+        "    #",
+        "   # ",
+        "  #  ",
+        "  #  ",
+    ]),
+    ('8', [
+        " ### ",
+        "#   #",
+        " ### ",
+        "#   #",
+        " ### ",
+    ]),
+    ('9', [
+        " ### ",
+        "#   #",
+        " ####",
+        "    #",
+        " ### ",
+    ]),
+    (':', [
+        " ",
+        "#",
+        " ",
+        "#",
+        " ",
+    ]),
+    ('?', [
+        " ### ",
+        "#   #",
+        "  ## ",
+        "     ",
+        "  #  ",
+    ]),
+    ('A', [
+        " ### ",
+        "#   #",
+        "#####",
+        "#   #",
+        "#   #",
+    ]),
+    ('B', [
+        "#### ",
+        "#   #",
+        "#### ",
+        "#   #",
+        "#### ",
+    ]),
+    ('C', [
+        " ####",
+        "#    ",
+        "#    ",
+        "#    ",
+        " ####",
+    ]),
+    ('D', [
+        "#### ",
+        "#   #",
+        "#   #",
+        "#   #",
+        "#### ",
+    ]),
+    ('E', [
+        "#####",
+        "#    ",
+        "#### ",
+        "#    ",
+        "#####",
+    ]),
+    ('F', [
+        "#####",
+        "#    ",
+        "#### ",
+        "#    ",
+        "#    ",
+    ]),
+    ('G', [
+        " ####",
+        "#    ",
+        "#  ##",
+        "#   #",
+        " ####",
+    ]),
+    ('H', [
+        "#   #",
+        "#   #",
+        "#####",
+        "#   #",
+        "#   #",
+    ]),
+    ('I', [
+        "###",
+        " # ",
+        " # ",
+        " # ",
+        "###",
+    ]),
+    ('J', [
+        "  ###",
+        "   # ",
+        "   # ",
+        "#  # ",
+        " ##  ",
+    ]),
+    ('K', [
+        "#   #",
+        "#  # ",
+        "###  ",
+        "#  # ",
+        "#   #",
+    ]),
+    ('L', [
+        "#    ",
+        "#    ",
+        "#    ",
+        "#    ",
+        "#####",
+    ]),
+    ('M', [
+        "#   #",
+        "## ##",
+        "# # #",
+        "#   #",
+        "#   #",
+    ]),
+    ('N', [
+        "#   #",
+        "##  #",
+        "# # #",
+        "#  ##",
+        "#   #",
+    ]),
+    ('O', [
+        " ### ",
+        "#   #",
+        "#   #",
+        "#   #",
+        " ### ",
+    ]),
+    ('P', [
+        "#### ",
+        "#   #",
+        "#### ",
+        "#    ",
+        "#    ",
+    ]),
+    ('Q', [
+        " ### ",
+        "#   #",
+        "# # #",
+        "#  # ",
+        " ## #",
+    ]),
+    ('R', [
+        "#### ",
+        "#   #",
+        "#### ",
+        "#  # ",
+        "#   #",
+    ]),
+    ('S', [
+        " ####",
+        "#    ",
+        " ### ",
+        "    #",
+        "#### ",
+    ]),
+    ('T', [
+        "#####",
+        "  #  ",
+        "  #  ",
+        "  #  ",
+        "  #  ",
+    ]),
+    ('U', [
+        "#   #",
+        "#   #",
+        "#   #",
+        "#   #",
+        " ### ",
+    ]),
+    ('V', [
+        "#   #",
+        "#   #",
+        "#   #",
+        " # # ",
+        "  #  ",
+    ]),
+    ('W', [
+        "#   #",
+        "#   #",
+        "# # #",
+        "## ##",
+        "#   #",
+    ]),
+    ('X', [
+        "#   #",
+        " # # ",
+        "  #  ",
+        " # # ",
+        "#   #",
+    ]),
+    ('Y', [
+        "#   #",
+        " # # ",
+        "  #  ",
+        "  #  ",
+        "  #  ",
+    ]),
+    ('Z', [
+        "#####",
+        "   # ",
+        "  #  ",
+        " #   ",
+        "#####",
+    ]),
+];
+
+/// Looks up the glyph for `c`, ignoring case.
+///
+/// Returns `None` for characters the font doesn't cover.
+pub(crate) fn glyph(c: char) -> Option<&'static [&'static str; HEIGHT]> {
+    let c = c.to_ascii_uppercase();
+    GLYPHS
+        .binary_search_by_key(&c, |(key, _)| *key)
+        .ok()
+        .map(|i| &GLYPHS[i].1)
+}
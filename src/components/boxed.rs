@@ -1,4 +1,12 @@
-use crate::layout::pad;
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::components::note::{add_shadow, get_border, get_junctions};
+use crate::layout::{
+    Align, default_width, indent_lines, pad_align, terminal_width, truncate, visible_width,
+};
+use crate::style::paint;
+use crate::theme::theme;
+use ansi_term::Style;
 
 /// A bordered container for displaying content with a title.
 ///
@@ -19,21 +27,52 @@ pub struct Boxed {
     title: String,
     content: String,
     width: usize,
+    auto_width: bool,
+    margin: (usize, usize, usize, usize),
+    max_height: Option<usize>,
+    scroll: usize,
+    style: String,
+    bg: Option<String>,
+    padding: (usize, usize),
+    wrap: bool,
+    indent: usize,
+    hanging_indent: usize,
+    align: Align,
+    title_align: Align,
+    shadow: bool,
 }
 
 impl Boxed {
     /// Creates a new `Boxed` instance with the given title.
     ///
-    /// The default width is 50 characters, and the content is initially empty.
+    /// The default width is 50 characters inside the borders, or the width
+    /// set with `set_default_width` with the borders included, and the
+    /// content is initially empty.
     ///
     /// # Arguments
     ///
     /// * `title` - The title to display at the top of the box.
-    pub fn new(title: &str) -> Self {
+    pub fn new(title: impl Into<String>) -> Self {
         Self {
-            title: title.to_string(),
+            title: title.into(),
             content: String::new(),
-            width: 50,
+            // The borders are drawn outside `width`
+            width: default_width().map(|w| w.saturating_sub(2)).unwrap_or(50),
+            auto_width: false,
+            margin: (0, 0, 0, 0),
+            max_height: None,
+            scroll: 0,
+            style: theme()
+                .border_style
+                .unwrap_or_else(|| "rounded".to_string()),
+            bg: None,
+            padding: (0, 0),
+            wrap: false,
+            indent: 0,
+            hanging_indent: 0,
+            align: Align::Left,
+            title_align: Align::Left,
+            shadow: false,
         }
     }
 
@@ -46,8 +85,8 @@ impl Boxed {
     /// # Arguments
     ///
     /// * `text` - The string slice containing the content for the box.
-    pub fn content(mut self, text: &str) -> Self {
-        self.content = text.to_string();
+    pub fn content(mut self, text: impl Into<String>) -> Self {
+        self.content = text.into();
         self
     }
 
@@ -55,27 +94,539 @@ impl Boxed {
     ///
     /// This method consumes `self` and returns a new `Boxed` instance,
     /// allowing for method chaining. The content and title will be
-    /// padded to fit this width.
+    /// padded to fit this width. A title too wide for the box is truncated
+    /// with `…` so the borders stay aligned; use `min_width` to grow the box
+    /// instead.
     ///
     /// # Arguments
     ///
     /// * `width` - The desired total width of the box in characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Boxed;
+    ///
+    /// let boxed = Boxed::new("A very long title").style("+").width(10).render();
+    /// assert_eq!(
+    ///     boxed,
+    ///     "+----------+\n| A very … |\n+----------+\n+----------+"
+    /// );
+    /// ```
     pub fn width(mut self, width: usize) -> Self {
         self.width = width;
         self
     }
 
+    /// Sets the smallest width of the box, letting it grow to fit its text.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining. The box is drawn at `width`, or wider
+    /// if the title or the longest content line needs more room, so nothing
+    /// is truncated. Calling `width` afterwards changes the minimum without
+    /// turning growth off.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The minimum width of the box in characters.
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with the updated width setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Boxed;
+    ///
+    /// let boxed = Boxed::new("A very long title").style("+").min_width(10).render();
+    /// assert_eq!(
+    ///     boxed,
+    ///     "+-------------------+\n| A very long title |\n+-------------------+\n+-------------------+"
+    /// );
+    /// ```
+    pub fn min_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self.auto_width = true;
+        self
+    }
+
+    /// Sizes the box to span the full width of the terminal.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining. The width is read from the terminal
+    /// when this method is called, and the borders are included so the box
+    /// fills the line exactly. When standard output isn't a terminal, the
+    /// default width is used instead: 50 inside the borders, or the width set
+    /// with `set_default_width` with the borders included.
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with its width set to the terminal width.
+    pub fn full_width(mut self) -> Self {
+        // The borders are drawn outside `width`
+        self.width = terminal_width()
+            .map(|w| w.saturating_sub(2))
+            .or_else(|| default_width().map(|w| w.saturating_sub(2)))
+            .unwrap_or(50);
+        self
+    }
+
+    /// Sets the outer margins around the box.
+    ///
+    /// Top and bottom margins add blank lines above and below the box, while
+    /// left and right margins add blank columns beside it, shifting the box
+    /// right or reserving space after it. Unlike padding, margins sit outside
+    /// the borders.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `top` - The number of blank lines above the box.
+    /// * `right` - The number of blank columns after the box.
+    /// * `bottom` - The number of blank lines below the box.
+    /// * `left` - The number of blank columns before the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Boxed;
+    ///
+    /// let rendered = Boxed::new("Hi").width(6).margin(1, 0, 0, 2).render();
+    /// assert_eq!(
+    ///     rendered,
+    ///     "          \n  ╭──────╮\n  │ Hi   │\n  ├──────┤\n  ╰──────╯"
+    /// );
+    /// ```
+    pub fn margin(mut self, top: usize, right: usize, bottom: usize, left: usize) -> Self {
+        self.margin = (top, right, bottom, left);
+        self
+    }
+
+    /// Sets the number of blank lines inside the box around the content.
+    ///
+    /// The blank lines sit between the title divider and the content, and
+    /// between the content and the bottom border. They are bordered and
+    /// filled like content lines, so the sides stay aligned. Unlike margins,
+    /// padding sits inside the borders. The default is no padding.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `top` - The number of blank lines above the content.
+    /// * `bottom` - The number of blank lines below the content.
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with the updated padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Boxed;
+    ///
+    /// let rendered = Boxed::new("Hi").content("ok").width(6).padding(1, 1).render();
+    /// assert_eq!(
+    ///     rendered,
+    ///     "╭──────╮\n│ Hi   │\n├──────┤\n│      │\n│ ok   │\n│      │\n╰──────╯"
+    /// );
+    /// ```
+    pub fn padding(mut self, top: usize, bottom: usize) -> Self {
+        self.padding = (top, bottom);
+        self
+    }
+
+    /// Enables or disables wrapping of long content lines.
+    ///
+    /// If `true`, lines wider than the box are wrapped at word boundaries to
+    /// fit between the borders and their padding spaces. If `false`
+    /// (default), lines only break at explicit newline characters (`\n`).
+    /// Wrapping happens before `max_height` is applied, so the scroll window
+    /// counts wrapped lines.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `wrap` - A boolean indicating whether to enable (`true`) or disable (`false`) wrapping.
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with the updated wrap setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::{Align, Boxed};
+    ///
+    /// let rendered = Boxed::new("Tip")
+    ///     .content("Wrap me nicely")
+    ///     .width(10)
+    ///     .wrap(true)
+    ///     .align(Align::Center)
+    ///     .render();
+    /// assert_eq!(
+    ///     rendered,
+    ///     "╭──────────╮\n\
+    ///      │ Tip      │\n\
+    ///      ├──────────┤\n\
+    ///      │ Wrap me  │\n\
+    ///      │  nicely  │\n\
+    ///      ╰──────────╯"
+    /// );
+    /// ```
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets how many spaces every content line is indented by.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining. When wrapping is enabled, lines wrap
+    /// early enough to fit after the indent. The indent is clamped to the
+    /// interior of the box.
+    ///
+    /// # Arguments
+    ///
+    /// * `spaces` - The number of spaces to put before each line.
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with the updated indent.
+    pub fn indent(mut self, spaces: usize) -> Self {
+        self.indent = spaces;
+        self
+    }
+
+    /// Sets how many extra spaces the continuation lines of wrapped paragraphs are indented by.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining. The first line of each paragraph stays
+    /// flush, which suits definition-style text. It only has an effect when
+    /// wrapping is enabled, and adds to any `indent`.
+    ///
+    /// # Arguments
+    ///
+    /// * `spaces` - The number of extra spaces before each continuation line.
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with the updated hanging indent.
+    pub fn hanging_indent(mut self, spaces: usize) -> Self {
+        self.hanging_indent = spaces;
+        self
+    }
+
+    /// Sets the horizontal alignment of the content lines.
+    ///
+    /// Lines are aligned within the interior of the box, measured by display
+    /// width. The default is `Align::Left`.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `align` - The `Align` to apply to the content.
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with the updated content alignment.
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets the horizontal alignment of the title.
+    ///
+    /// The default is `Align::Left`.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `align` - The `Align` to apply to the title.
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with the updated title alignment.
+    pub fn title_align(mut self, align: Align) -> Self {
+        self.title_align = align;
+        self
+    }
+
+    /// Limits the number of content lines shown inside the box.
+    ///
+    /// When the content has more lines than `height`, only a window of
+    /// `height` lines is drawn (starting at the `scroll` offset) and the
+    /// bottom border shows which lines are visible, e.g. `[3-10/42]`. The
+    /// title and the three border lines are drawn in addition, so the box is
+    /// `height + 4` lines tall. A `height` of `0` is clamped to `1`, so at
+    /// least one content line is always shown.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The maximum number of content lines to display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Boxed;
+    ///
+    /// let rendered = Boxed::new("Log")
+    ///     .content("one\ntwo\nthree\nfour")
+    ///     .width(14)
+    ///     .max_height(2)
+    ///     .scroll(1)
+    ///     .render();
+    /// assert_eq!(
+    ///     rendered,
+    ///     "╭──────────────╮\n\
+    ///      │ Log          │\n\
+    ///      ├──────────────┤\n\
+    ///      │ two          │\n\
+    ///      │ three        │\n\
+    ///      ╰──────[2-3/4]─╯"
+    /// );
+    /// ```
+    ///
+    /// A zero height and a scroll offset past the end are both clamped:
+    /// ```
+    /// use cliux::Boxed;
+    ///
+    /// let rendered = Boxed::new("Log")
+    ///     .content("one\ntwo\nthree")
+    ///     .width(14)
+    ///     .max_height(0)
+    ///     .scroll(9)
+    ///     .render();
+    /// assert_eq!(
+    ///     rendered,
+    ///     "╭──────────────╮\n\
+    ///      │ Log          │\n\
+    ///      ├──────────────┤\n\
+    ///      │ three        │\n\
+    ///      ╰──────[3-3/3]─╯"
+    /// );
+    /// ```
+    pub fn max_height(mut self, height: usize) -> Self {
+        self.max_height = Some(height.max(1));
+        self
+    }
+
+    /// Sets the index of the first content line shown when `max_height` is set.
+    ///
+    /// Offsets past the end of the content are clamped so the last full
+    /// window is shown instead.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The zero-based index of the first visible content line.
+    pub fn scroll(mut self, offset: usize) -> Self {
+        self.scroll = offset;
+        self
+    }
+
+    /// Sets the border style for the box.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining. Supports the same styles as `Note`:
+    /// `"rounded"` (default), `"square"`, and `"+"` for ASCII-only output.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - A string slice representing the desired border style.
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with the updated border style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Boxed;
+    ///
+    /// let boxed = Boxed::new("Hi").content("ok").width(6).style("+").render();
+    ///
+    /// assert_eq!(boxed, "+------+\n| Hi   |\n+------+\n| ok   |\n+------+");
+    /// ```
+    pub fn style(mut self, style: &str) -> Self {
+        self.style = style.to_string();
+        self
+    }
+
+    /// Sets the background color of the box.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining. Accepts the same color names, hex
+    /// strings, and 256-color indices as `Note::color`. The background fills
+    /// the whole interior between the borders, not just the text, and is
+    /// dropped when color output is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired background color.
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with the updated background color.
+    pub fn bg(mut self, color: &str) -> Self {
+        self.bg = Some(color.to_string());
+        self
+    }
+
+    /// Sets whether the box casts a drop shadow.
+    ///
+    /// This method consumes `self` and returns a new `Boxed` instance,
+    /// allowing for method chaining. The shadow is drawn one column to the
+    /// right of the box and one row below it, inside any margin, so it adds
+    /// one column and one line to the rendered size. It is a dim `▒`, or a
+    /// gray background when Unicode is disabled. Without color output it is
+    /// drawn as plain `▒` characters or spaces, so the size stays the same.
+    ///
+    /// # Arguments
+    ///
+    /// * `shadow` - A boolean indicating whether to draw the shadow (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Boxed` instance with the updated shadow setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Boxed;
+    ///
+    /// let rendered = Boxed::new("Hi").content("ok").width(6).shadow(true).render();
+    /// assert_eq!(
+    ///     rendered,
+    ///     "╭──────╮ \n│ Hi   │▒\n├──────┤▒\n│ ok   │▒\n╰──────╯▒\n ▒▒▒▒▒▒▒▒"
+    /// );
+    /// ```
+    pub fn shadow(mut self, shadow: bool) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
+    /// Renders the `Boxed` content into a `String`.
+    ///
+    /// The output is identical to what `print` writes, including margins,
+    /// with lines separated by newlines and no trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered box.
+    pub fn render(&self) -> String {
+        let (top, right, bottom, left) = self.margin;
+        let width = self.box_width();
+        let inner = width.saturating_sub(2);
+
+        let (tl, tr, bl, br, h, v) = get_border(&self.style);
+        let (_, _, join_left, join_right, _) = get_junctions(&self.style);
+        let horizontal = h.repeat(width);
+        let mut fill = Style::new();
+        if let Some(colour) = self.bg.as_deref().and_then(parse_colour) {
+            fill = fill.on(colour);
+        }
+
+        let mut lines = vec![
+            format!("{}{}{}", tl, horizontal, tr),
+            format!(
+                "{}{}{}",
+                v,
+                paint(
+                    &fill,
+                    &format!(
+                        " {} ",
+                        pad_align(&truncate(&self.title, inner), inner, self.title_align)
+                    )
+                ),
+                v
+            ),
+            format!("{}{}{}", join_left, horizontal, join_right),
+        ];
+        let content = indent_lines(
+            &self.content,
+            inner,
+            self.wrap,
+            self.indent,
+            self.hanging_indent,
+        );
+        let total = content.len();
+        let height = self.max_height.unwrap_or(total).min(total);
+        let start = self.scroll.min(total - height);
+        let blank = format!("{}{}{}", v, paint(&fill, &" ".repeat(width)), v);
+
+        lines.extend(std::iter::repeat_n(blank.clone(), self.padding.0));
+        for line in &content[start..start + height] {
+            let interior = format!(" {} ", pad_align(line, inner, self.align));
+            lines.push(format!("{}{}{}", v, paint(&fill, &interior), v));
+        }
+        lines.extend(std::iter::repeat_n(blank, self.padding.1));
+
+        // Show which lines are visible when the content doesn't fit
+        let indicator = format!("[{}-{}/{}]", start + 1, start + height, total);
+        if height < total && indicator.len() < width {
+            let run = width - indicator.len() - 1;
+            lines.push(format!("{}{}{}{}{}", bl, h.repeat(run), indicator, h, br));
+        } else {
+            lines.push(format!("{}{}{}", bl, horizontal, br));
+        }
+        if self.shadow {
+            lines = add_shadow(lines, width + 2);
+        }
+
+        let blank = " ".repeat(left + width + 2 + usize::from(self.shadow) + right);
+        let mut out = vec![blank.clone(); top];
+        out.extend(
+            lines
+                .into_iter()
+                .map(|line| format!("{}{}{}", " ".repeat(left), line, " ".repeat(right))),
+        );
+        out.extend(vec![blank; bottom]);
+        out.join("\n")
+    }
+
+    /// Returns the width to draw the box at, grown to fit its text if `min_width` is set.
+    fn box_width(&self) -> usize {
+        if !self.auto_width {
+            return self.width;
+        }
+        let widest = self
+            .content
+            .lines()
+            .map(|line| self.indent + visible_width(line))
+            .chain([visible_width(&self.title)])
+            .max()
+            .unwrap_or(0);
+        // One space of padding on each side of the text
+        self.width.max(widest + 2)
+    }
+
     /// Prints the `Boxed` content to the console.
     ///
     /// This method renders the box with its title, borders, and content
     /// to standard output.
     pub fn print(&self) {
-        println!("╭{:─<1$}╮", "", self.width);
-        println!("│ {} │", pad(&self.title, self.width - 2));
-        println!("├{:─<1$}┤", "", self.width);
-        for line in self.content.lines() {
-            println!("│ {} │", pad(line, self.width - 2));
-        }
-        println!("╰{:─<1$}╯", "", self.width);
+        Render::print(self)
+    }
+}
+
+impl Render for Boxed {
+    fn render(&self) -> String {
+        Boxed::render(self)
     }
 }
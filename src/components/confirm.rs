@@ -1,24 +1,36 @@
 use crate::components::note::get_border;
-use crate::layout::pad;
+use crate::layout::{default_width, pad};
+use crate::style::paint;
+use crate::theme::theme;
+use ansi_term::Style;
+use std::io::{self, Write};
 
 pub struct Confirm {
     label: String,
     default: Option<bool>,
     color: Option<String>,
+    bg: Option<String>,
     bold: bool,
     style: Option<String>,
     width: usize,
+    symbol: String,
+    max_attempts: Option<usize>,
 }
 
 impl Confirm {
-    pub fn new(label: &str) -> Self {
+    pub fn new(label: impl Into<String>) -> Self {
+        let theme = theme();
         Self {
-            label: label.to_string(),
+            label: label.into(),
             default: None,
-            color: None,
+            color: theme.accent_color,
+            bg: None,
             bold: false,
-            style: Some("square".to_string()),
-            width: 40,
+            style: Some(theme.border_style.unwrap_or_else(|| "square".to_string())),
+            // The borders are drawn outside `width`
+            width: default_width().map(|w| w.saturating_sub(2)).unwrap_or(40),
+            symbol: "> ".to_string(),
+            max_attempts: None,
         }
     }
 
@@ -32,6 +44,26 @@ impl Confirm {
         self
     }
 
+    /// Sets the background color of the label line.
+    ///
+    /// This method consumes `self` and returns a new `Confirm` instance,
+    /// allowing for method chaining. Accepts the same color names, hex
+    /// strings, and 256-color indices as `color`. Like all styling, the
+    /// background is dropped when color output is disabled. The
+    /// background fills the full width of the box.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired background color.
+    ///
+    /// # Returns
+    ///
+    /// The `Confirm` instance with the updated background color.
+    pub fn bg(mut self, color: &str) -> Self {
+        self.bg = Some(color.to_string());
+        self
+    }
+
     pub fn bold(mut self, bold: bool) -> Self {
         self.bold = bold;
         self
@@ -47,46 +79,187 @@ impl Confirm {
         self
     }
 
+    /// Sets the symbol printed before the cursor on the input line.
+    ///
+    /// The default is `"> "`. The symbol is painted with the same color and
+    /// boldness as the label, and the cursor is left directly after it.
+    ///
+    /// This method consumes `self` and returns a new `Confirm` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The string slice to print before the cursor (e.g. `"❯ "`, `"$ "`).
+    ///
+    /// # Returns
+    ///
+    /// The `Confirm` instance with the updated prompt symbol.
+    pub fn prompt_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = symbol.into();
+        self
+    }
+
+    /// Limits how many times an invalid answer is asked again.
+    ///
+    /// By default the prompt keeps asking until it gets a valid answer.
+    ///
+    /// This method consumes `self` and returns a new `Confirm` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempts` - The maximum number of answers to read, including the first.
+    ///
+    /// # Returns
+    ///
+    /// The `Confirm` instance with the updated attempt limit.
+    pub fn max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Shows the prompt and reads a yes or no answer.
+    ///
+    /// This is `try_prompt` for callers that can't recover from I/O errors.
+    /// When standard input is closed or every attempt was invalid, the
+    /// default (or `false`) is returned.
+    ///
+    /// # Returns
+    ///
+    /// `true` for "y" or "yes", `false` for "n" or "no", and the default
+    /// (or `false`) for an empty answer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if reading from standard input or writing to standard output fails.
     pub fn prompt(&self) -> bool {
-        use ansi_term::Style;
-        use std::io::{self, Write};
+        match self.try_prompt() {
+            Ok(answer) => answer,
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::UnexpectedEof | io::ErrorKind::InvalidInput
+                ) =>
+            {
+                self.default.unwrap_or(false)
+            }
+            Err(e) => panic!("failed to read input: {}", e),
+        }
+    }
 
-        let (tl, tr, bl, br, h, v) = get_border(self.style.as_deref().unwrap_or("plain"));
-        let mut style = Style::new();
+    /// Shows the prompt and reads a yes or no answer, returning any I/O error.
+    ///
+    /// Answers are case-insensitive. An empty line picks the default (or
+    /// `false`). Anything other than yes, no, or an empty line prints a hint
+    /// and asks again, up to the limit set by `max_attempts`.
+    ///
+    /// # Returns
+    ///
+    /// `true` for "y" or "yes", `false` for "n" or "no", and the default
+    /// (or `false`) for an empty answer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from standard input or flushing standard
+    /// output fails. If standard input is closed (EOF) before an answer is
+    /// given, the default is returned, or an `UnexpectedEof` error when
+    /// there is no default. If every attempt allowed by `max_attempts` is
+    /// invalid, an `InvalidInput` error is returned.
+    pub fn try_prompt(&self) -> io::Result<bool> {
+        let style = self.text_style();
+        println!("{}", self.render_prompt());
 
-        if let Some(ref color) = self.color {
-            if let Some(colour) = crate::components::label::parse_colour(color) {
-                style = style.fg(colour);
+        let mut attempts = 0;
+        loop {
+            // Input line
+            print!("{}", paint(&style, &self.symbol));
+            io::stdout().flush()?;
+
+            // A closed stdin reads zero bytes, unlike an empty line
+            let mut input = String::new();
+            if io::stdin().read_line(&mut input)? == 0 {
+                println!();
+                return self.default.ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "stdin closed before an answer",
+                    )
+                });
             }
+
+            match input.trim().to_lowercase().as_str() {
+                "y" | "yes" => return Ok(true),
+                "n" | "no" => return Ok(false),
+                "" => return Ok(self.default.unwrap_or(false)),
+                _ => {}
+            }
+
+            attempts += 1;
+            if self.max_attempts.is_some_and(|max| attempts >= max) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("no valid answer after {} attempts", attempts),
+                ));
+            }
+            println!("Invalid input. Please enter y or n.");
+        }
+    }
+
+    /// Renders the boxed question shown by `prompt`, without reading an answer.
+    ///
+    /// The label is followed by the choices, with the default capitalized:
+    /// `(Y/n)` when the default is yes, `(y/N)` when it is no, and `(y/n)`
+    /// when there is no default.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered box, with no trailing newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Confirm;
+    ///
+    /// let question = Confirm::new("Continue?").default(true).style("+").width(16).render_prompt();
+    /// assert_eq!(
+    ///     question,
+    ///     "+----------------+\n|Continue? (Y/n) |\n+----------------+"
+    /// );
+    /// ```
+    pub fn render_prompt(&self) -> String {
+        let (tl, tr, bl, br, h, v) = get_border(self.style.as_deref().unwrap_or("plain"));
+        let choices = match self.default {
+            Some(true) => "(Y/n)",
+            Some(false) => "(y/N)",
+            None => "(y/n)",
+        };
+        let padded_label = pad(&format!("{} {}", self.label, choices), self.width);
+        let styled_label = paint(&self.text_style(), &padded_label);
+
+        [
+            format!("{}{}{}", tl, h.repeat(self.width), tr),
+            format!("{}{}{}", v, styled_label, v),
+            format!("{}{}{}", bl, h.repeat(self.width), br),
+        ]
+        .join("\n")
+    }
+
+    /// Builds the style shared by the label and the input symbol.
+    fn text_style(&self) -> Style {
+        let mut style = Style::new();
+        if let Some(ref color) = self.color
+            && let Some(colour) = crate::components::label::parse_colour(color)
+        {
+            style = style.fg(colour);
+        }
+        if let Some(ref color) = self.bg
+            && let Some(colour) = crate::components::label::parse_colour(color)
+        {
+            style = style.on(colour);
         }
         if self.bold {
             style = style.bold();
         }
-
-        let padded_label = pad(&format!("{} (y/n)", self.label), self.width);
-        let styled_label = style.paint(padded_label).to_string();
-
-        // Draw box
-        println!("{}{}{}", tl, h.to_string().repeat(self.width), tr);
-        println!("{}{}{}", v, styled_label, v);
-        println!("{}{}{}", bl, h.to_string().repeat(self.width), br);
-
-        // Input line
-        print!("> ");
-        io::stdout().flush().unwrap();
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap();
-        let input = input.trim().to_lowercase();
-
-        match input.as_str() {
-            "y" | "yes" => true,
-            "n" | "no" => false,
-            "" => self.default.unwrap_or(false),
-            _ => {
-                println!("Invalid input. Please enter y or n.");
-                self.prompt()
-            }
-        }
+        style
     }
 }
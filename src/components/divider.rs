@@ -1,3 +1,11 @@
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::layout::{Align, default_width, display_width, terminal_width, truncate, visible_width};
+use crate::style::{paint, unicode_enabled};
+use crate::theme::theme;
+use ansi_term::Style;
+use unicode_segmentation::UnicodeSegmentation;
+
 /// A customizable horizontal line for separating sections of output.
 ///
 /// The `Divider` struct allows you to easily create horizontal rules
@@ -15,12 +23,32 @@
 ///
 /// // A divider using '=' characters
 /// Divider::new(30).style('=').print();
+///
+/// // A repeating pattern
+/// Divider::new(30).pattern("=-").print();
+///
+/// // A shaded rule that fades in and out
+/// Divider::new(30).shaded().print();
+///
+/// // A rule with a centered label
+/// Divider::new(30).label("Results").print();
 /// ```
 pub struct Divider {
     width: usize,
-    style: char, // e.g. '─', '=', '.', etc.
+    pattern: String, // e.g. "─", "=", "- ", etc.
+    shaded: bool,
+    ascii: bool,
+    label: Option<String>,
+    align: Align,
+    color: Option<String>,
 }
 
+/// Shade glyphs from lightest to darkest, used by `Divider::shaded`.
+const SHADES: [char; 4] = ['░', '▒', '▓', '█'];
+
+/// ASCII stand-ins for `SHADES`, used when `Divider::ascii` is enabled.
+const ASCII_SHADES: [char; 4] = ['.', ':', '=', '#'];
+
 impl Divider {
     /// Creates a new `Divider` instance with the specified width.
     ///
@@ -35,14 +63,21 @@ impl Divider {
     /// A new `Divider` instance.
     pub fn new(width: usize) -> Self {
         Self {
-            width, style: '─'
+            width,
+            pattern: theme().divider.to_string(),
+            shaded: false,
+            ascii: false,
+            label: None,
+            align: Align::Center,
+            color: None,
         }
     }
 
     /// Sets the character used to draw the divider.
     ///
     /// This method consumes `self` and returns a new `Divider` instance,
-    /// allowing for method chaining.
+    /// allowing for method chaining. It is shorthand for `pattern` with a
+    /// single character.
     ///
     /// # Arguments
     ///
@@ -51,16 +86,335 @@ impl Divider {
     /// # Returns
     ///
     /// The `Divider` instance with the updated style.
-    pub fn style(mut self, style: char) -> Self {
-        self.style = style;
+    pub fn style(self, style: char) -> Self {
+        self.pattern(&style.to_string())
+    }
+
+    /// Sets a string that is repeated to draw the divider.
+    ///
+    /// This method consumes `self` and returns a new `Divider` instance,
+    /// allowing for method chaining. The pattern is tiled by display width,
+    /// so patterns containing wide characters or emoji still fill exactly
+    /// `width` columns. The last repeat is cut short to fit, and a wide
+    /// character that would overrun the width is replaced by spaces. An empty
+    /// pattern draws an empty line.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The string slice to repeat (e.g., `"- "`, `"·‧"`, `"=-"`).
+    ///
+    /// # Returns
+    ///
+    /// The `Divider` instance with the updated pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Divider;
+    ///
+    /// assert_eq!(Divider::new(7).pattern("=-").render(), "=-=-=-=");
+    /// assert_eq!(Divider::new(5).pattern("🌊").render(), "🌊🌊 ");
+    /// assert_eq!(Divider::new(5).pattern("").render(), "");
+    /// ```
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        self.pattern = pattern.to_string();
+        self
+    }
+
+    /// Sets the line weight of the divider by name.
+    ///
+    /// This method consumes `self` and returns a new `Divider` instance,
+    /// allowing for method chaining. It is a shorthand for `style` with the
+    /// matching box-drawing character:
+    ///
+    /// * `"single"` - `─`
+    /// * `"double"` - `═`
+    /// * `"thick"` - `━`
+    /// * `"dashed"` - `╌`
+    /// * `"dotted"` - `┄`
+    ///
+    /// When restricted to ASCII, double rules are drawn with `=` and the
+    /// others with `-`. Unrecognized names leave the divider unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - A string slice naming the line weight.
+    ///
+    /// # Returns
+    ///
+    /// The `Divider` instance with the updated pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Divider;
+    ///
+    /// assert_eq!(Divider::new(5).weight("double").render(), "═════");
+    /// assert_eq!(Divider::new(5).weight("thick").ascii(true).render(), "-----");
+    /// ```
+    pub fn weight(self, weight: &str) -> Self {
+        match weight {
+            "single" => self.style('─'),
+            "double" => self.style('═'),
+            "thick" => self.style('━'),
+            "dashed" => self.style('╌'),
+            "dotted" => self.style('┄'),
+            _ => self,
+        }
+    }
+
+    /// Draws the divider as a shaded rule instead of a repeated character.
+    ///
+    /// The rule fades symmetrically from light to dark and back again
+    /// (`░▒▓█▓▒░`) across the full width. Each shade glyph occupies a single
+    /// column, so the rule always fills exactly `width` columns.
+    ///
+    /// This method consumes `self` and returns a new `Divider` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Returns
+    ///
+    /// The `Divider` instance configured as a shaded rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Divider;
+    ///
+    /// assert_eq!(Divider::new(9).shaded().render(), "░▒▓▓█▓▓▒░");
+    /// assert_eq!(Divider::new(9).shaded().ascii(true).render(), ".:==#==:.");
+    /// ```
+    pub fn shaded(mut self) -> Self {
+        self.shaded = true;
+        self
+    }
+
+    /// Sets whether the divider should be restricted to ASCII characters.
+    ///
+    /// When enabled, a shaded rule uses `.:=#` in place of the block shade
+    /// glyphs, and rules drawn with a `weight` fall back to `=` or `-`, for
+    /// terminals or fonts that can't render them.
+    ///
+    /// This method consumes `self` and returns a new `Divider` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `ascii` - A boolean indicating whether to use ASCII-only glyphs (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Divider` instance with the updated ASCII setting.
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Sets a label to embed in the rule, such as `──── Section ────`.
+    ///
+    /// This method consumes `self` and returns a new `Divider` instance,
+    /// allowing for method chaining. The label is surrounded by a space on
+    /// each side and keeps at least one rule character on either side. If
+    /// it doesn't fit, it is truncated with `…` so the divider stays exactly
+    /// `width` columns wide. Dividers narrower than 5 columns have no room
+    /// for the spaces, so the truncated label fills them directly. Labels
+    /// are not drawn on shaded dividers.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The string slice to embed in the rule.
+    ///
+    /// # Returns
+    ///
+    /// The `Divider` instance with the label set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Divider;
+    ///
+    /// assert_eq!(Divider::new(12).style('-').label("Logs").render(), "--- Logs ---");
+    /// assert_eq!(Divider::new(10).style('-').label("Overview").render(), "- Overv… -");
+    /// assert_eq!(Divider::new(3).style('-').label("Overview").render(), "Ov…");
+    /// assert_eq!(Divider::new(4).style('-').label("Go").render(), "-Go-");
+    /// ```
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets where the label sits along the rule.
+    ///
+    /// This method consumes `self` and returns a new `Divider` instance,
+    /// allowing for method chaining. `Align::Center` (the default) splits
+    /// the rule evenly on both sides, giving any extra column to the right.
+    /// `Align::Left` and `Align::Right` keep a single rule character before
+    /// or after the label.
+    ///
+    /// # Arguments
+    ///
+    /// * `align` - The `Align` to apply to the label.
+    ///
+    /// # Returns
+    ///
+    /// The `Divider` instance with the updated label alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::{Align, Divider};
+    ///
+    /// let rule = Divider::new(12).style('=').label("Logs").align(Align::Left);
+    /// assert_eq!(rule.render(), "= Logs =====");
+    /// ```
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets the color of the label.
+    ///
+    /// This method consumes `self` and returns a new `Divider` instance,
+    /// allowing for method chaining. Accepts the same colors as `Label::color`.
+    /// The rule itself is not colored.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `Divider` instance with the updated label color.
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Sizes the divider to span the full width of the terminal.
+    ///
+    /// This method consumes `self` and returns a new `Divider` instance,
+    /// allowing for method chaining. The width is read from the terminal
+    /// when this method is called. When standard output isn't a
+    /// terminal, the default width is used instead: 50, or the width set
+    /// with `set_default_width`.
+    ///
+    /// # Returns
+    ///
+    /// The `Divider` instance with its width set to the terminal width.
+    pub fn full_width(mut self) -> Self {
+        self.width = terminal_width().or_else(default_width).unwrap_or(50);
         self
     }
 
+    /// Renders the `Divider` into a `String`.
+    ///
+    /// The output is identical to what `print` writes, without the trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered divider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Divider;
+    ///
+    /// assert_eq!(Divider::new(5).style('=').render(), "=====");
+    /// ```
+    pub fn render(&self) -> String {
+        if self.shaded {
+            return self.shade_line();
+        }
+        let ascii = self.ascii || !unicode_enabled();
+        let rule = match self.pattern.as_str() {
+            "═" if ascii => "=",
+            "─" | "━" | "╌" | "┄" if ascii => "-",
+            pattern => pattern,
+        };
+        match self.label {
+            Some(ref label) if self.width > 0 => self.label_line(rule, label),
+            _ => tile(rule, self.width),
+        }
+    }
+
+    /// Builds a rule of the `rule` pattern with `label` embedded at the chosen alignment.
+    fn label_line(&self, rule: &str, label: &str) -> String {
+        // The label gets a space and a rule character on each side when there is room
+        let (gap, edge) = if self.width >= 5 { (" ", 1) } else { ("", 0) };
+        let label = truncate(label, self.width - 2 * (gap.len() + edge));
+        let fill = self.width - 2 * gap.len() - visible_width(&label);
+        let left = match self.align {
+            Align::Left => edge,
+            Align::Center => fill / 2,
+            Align::Right => fill - edge,
+        };
+
+        let mut style = Style::new();
+        if let Some(colour) = self.color.as_deref().and_then(parse_colour) {
+            style = style.fg(colour);
+        }
+        format!(
+            "{}{}{}{}{}",
+            tile(rule, left),
+            gap,
+            paint(&style, &label),
+            gap,
+            tile(rule, fill - left)
+        )
+    }
+
     /// Prints the `Divider` to the console.
     ///
-    /// This method outputs a line of the chosen `style` character, repeated
-    /// `width` times, to standard output.
+    /// This method outputs a line of the chosen pattern, repeated to fill
+    /// `width` columns, to standard output. Shaded dividers output their fade instead.
     pub fn print(&self) {
-        println!("{}", self.style.to_string().repeat(self.width));
+        Render::print(self)
+    }
+
+    /// Builds the shaded rule, picking a darker glyph the closer a column is to the center.
+    fn shade_line(&self) -> String {
+        let glyphs = if self.ascii || !unicode_enabled() {
+            &ASCII_SHADES
+        } else {
+            &SHADES
+        };
+        let last = self.width.saturating_sub(1).max(1) as f64;
+        let darkest = (glyphs.len() - 1) as f64;
+
+        (0..self.width)
+            .map(|i| {
+                // 0.0 at either edge, 1.0 in the middle
+                let closeness = 1.0 - (2.0 * i as f64 / last - 1.0).abs();
+                glyphs[(closeness * darkest).round() as usize]
+            })
+            .collect()
+    }
+}
+
+impl Render for Divider {
+    fn render(&self) -> String {
+        Divider::render(self)
+    }
+}
+
+/// Repeats `pattern` to fill exactly `width` columns, measured by display width.
+///
+/// A wide character that would overrun the width is replaced by spaces. Patterns
+/// with no visible width, including the empty string, give an empty line.
+fn tile(pattern: &str, width: usize) -> String {
+    if display_width(pattern) == 0 {
+        return String::new();
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for g in pattern.graphemes(true).cycle() {
+        let w = display_width(g);
+        if used + w > width {
+            break;
+        }
+        out.push_str(g);
+        used += w;
     }
+    out.push_str(&" ".repeat(width - used));
+    out
 }
@@ -1,31 +1,53 @@
 use crate::components::note::get_border;
-use crate::layout::pad;
+use crate::layout::{default_width, pad};
+use crate::style::paint;
+use crate::theme::theme;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use std::fmt::Display;
+use std::io::{self, IsTerminal, Write};
+use std::str::FromStr;
 
 pub struct Input {
     label: String,
     default: Option<String>,
     color: Option<String>,
+    bg: Option<String>,
     bold: bool,
     mask: bool,
+    mask_char: Option<char>,
     style: Option<String>,
     width: usize,
+    symbol: String,
+    validator: Option<Validator>,
+    max_attempts: Option<usize>,
 }
 
+/// A check run on each answer, returning a message to show when it is rejected.
+type Validator = Box<dyn Fn(&str) -> Result<(), String>>;
+
 impl Input {
-    pub fn new(label: &str) -> Self {
+    pub fn new(label: impl Into<String>) -> Self {
+        let theme = theme();
         Self {
-            label: label.to_string(),
+            label: label.into(),
             default: None,
-            color: None,
+            color: theme.accent_color,
+            bg: None,
             bold: false,
             mask: false,
-            style: Some("rounded".to_string()),
-            width: 40,
+            mask_char: None,
+            style: Some(theme.border_style.unwrap_or_else(|| "rounded".to_string())),
+            // The borders are drawn outside `width`
+            width: default_width().map(|w| w.saturating_sub(2)).unwrap_or(40),
+            symbol: "> ".to_string(),
+            validator: None,
+            max_attempts: None,
         }
     }
 
-    pub fn default(mut self, value: &str) -> Self {
-        self.default = Some(value.to_string());
+    pub fn default(mut self, value: impl Into<String>) -> Self {
+        self.default = Some(value.into());
         self
     }
 
@@ -34,6 +56,26 @@ impl Input {
         self
     }
 
+    /// Sets the background color of the label line.
+    ///
+    /// This method consumes `self` and returns a new `Input` instance,
+    /// allowing for method chaining. Accepts the same color names, hex
+    /// strings, and 256-color indices as `color`. Like all styling, the
+    /// background is dropped when color output is disabled. The
+    /// background fills the full width of the box.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired background color.
+    ///
+    /// # Returns
+    ///
+    /// The `Input` instance with the updated background color.
+    pub fn bg(mut self, color: &str) -> Self {
+        self.bg = Some(color.to_string());
+        self
+    }
+
     pub fn bold(mut self, bold: bool) -> Self {
         self.bold = bold;
         self
@@ -44,6 +86,39 @@ impl Input {
         self
     }
 
+    /// Masks the input, echoing `mask` for every character typed.
+    ///
+    /// Plain `mask(true)` shows nothing while the user types. With a mask
+    /// character, the terminal is switched to raw mode so each keystroke can
+    /// be echoed as, e.g., `*`, and Backspace erases the last one. Ctrl-C or
+    /// Ctrl-D on an empty line ends the input as if standard input had been
+    /// closed, returning the default. The terminal mode is always restored
+    /// before the prompt returns.
+    ///
+    /// This method consumes `self` and returns a new `Input` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - The character to echo for each typed character.
+    ///
+    /// # Returns
+    ///
+    /// The `Input` instance with masking enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cliux::Input;
+    ///
+    /// let password = Input::new("Password").mask_char('*').prompt();
+    /// ```
+    pub fn mask_char(mut self, mask: char) -> Self {
+        self.mask = true;
+        self.mask_char = Some(mask);
+        self
+    }
+
     pub fn style(mut self, style: &str) -> Self {
         self.style = Some(style.to_string());
         self
@@ -54,46 +129,311 @@ impl Input {
         self
     }
 
+    /// Sets the symbol printed before the cursor on the input line.
+    ///
+    /// The default is `"> "`. The symbol is painted with the same color and
+    /// boldness as the label, and the cursor is left directly after it.
+    ///
+    /// This method consumes `self` and returns a new `Input` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The string slice to print before the cursor (e.g. `"❯ "`, `"$ "`).
+    ///
+    /// # Returns
+    ///
+    /// The `Input` instance with the updated prompt symbol.
+    pub fn prompt_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = symbol.into();
+        self
+    }
+
+    /// Sets a check that every answer must pass.
+    ///
+    /// After a line is read, and after an empty line is replaced by the
+    /// default, the validator is called with the answer. If it returns
+    /// `Err(message)`, the message is printed in red and the user is asked
+    /// again, so an invalid default is caught too.
+    ///
+    /// This method consumes `self` and returns a new `Input` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `validator` - A closure returning `Ok(())` to accept the answer or
+    ///   `Err(message)` to reject it.
+    ///
+    /// # Returns
+    ///
+    /// The `Input` instance with the validator set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cliux::Input;
+    ///
+    /// let email = Input::new("Email")
+    ///     .validate(|answer| {
+    ///         if answer.contains('@') {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("Please enter an email address.".to_string())
+    ///         }
+    ///     })
+    ///     .max_attempts(3)
+    ///     .prompt();
+    /// ```
+    pub fn validate(mut self, validator: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Limits how many times a rejected answer is asked again.
+    ///
+    /// By default the prompt keeps asking until the validator accepts an answer.
+    ///
+    /// This method consumes `self` and returns a new `Input` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempts` - The maximum number of answers to read, including the first.
+    ///
+    /// # Returns
+    ///
+    /// The `Input` instance with the updated attempt limit.
+    pub fn max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Shows the prompt and reads a line of input.
+    ///
+    /// This is `try_prompt` for callers that can't recover from I/O errors.
+    ///
+    /// # Returns
+    ///
+    /// The trimmed input, or the default when the input is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if reading from standard input or writing to standard output
+    /// fails, or if no answer passes the validator within `max_attempts`.
     pub fn prompt(&self) -> String {
-        use ansi_term::Style;
-        use std::io::{self, Write};
+        self.try_prompt().expect("failed to read input")
+    }
+
+    /// Shows the prompt and reads a line of input, returning any I/O error.
+    ///
+    /// When standard input is closed (EOF), for example in a non-interactive
+    /// pipeline, the input is treated as empty, so the default is returned.
+    /// Answers rejected by the `validate` check are asked again.
+    ///
+    /// # Returns
+    ///
+    /// The trimmed input, or the default (or an empty string when there is
+    /// no default) when the input is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from standard input or flushing standard
+    /// output fails. If the validator rejects the answer given at EOF, an
+    /// `UnexpectedEof` error is returned, and if it rejects every attempt
+    /// allowed by `max_attempts`, an `InvalidInput` error is returned.
+    pub fn try_prompt(&self) -> io::Result<String> {
+        self.ask(|answer| Ok(answer.to_string()))
+    }
+
+    /// Shows the prompt and parses the answer into `T`.
+    ///
+    /// The trimmed input, or the default when the input is empty, is parsed
+    /// with `str::parse`. Use `prompt_parse_retry` to ask again instead of
+    /// returning the parse error.
+    ///
+    /// # Returns
+    ///
+    /// The parsed answer, or the error returned by `T::from_str`.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as `prompt`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cliux::Input;
+    ///
+    /// let age = Input::new("Age?").prompt_parse::<u32>().unwrap_or(0);
+    /// ```
+    pub fn prompt_parse<T: FromStr>(&self) -> Result<T, T::Err> {
+        self.prompt().parse()
+    }
+
+    /// Shows the prompt until the answer parses into `T`.
+    ///
+    /// Answers that fail to parse, or that the `validate` check rejects,
+    /// print the error in red and are asked again, up to the limit set by
+    /// `max_attempts`. The default is parsed like any other answer.
+    ///
+    /// # Returns
+    ///
+    /// The first answer that parses into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `try_prompt`, with the last parse error as
+    /// the message when every attempt fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cliux::Input;
+    ///
+    /// let port: u16 = Input::new("Port")
+    ///     .default("8080")
+    ///     .max_attempts(3)
+    ///     .prompt_parse_retry()
+    ///     .expect("no valid port given");
+    /// ```
+    pub fn prompt_parse_retry<T>(&self) -> io::Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.ask(|answer| answer.parse().map_err(|e: T::Err| e.to_string()))
+    }
+
+    /// Runs the prompt loop, turning each valid answer into a `T` with `accept`.
+    ///
+    /// Answers are checked by the validator first. When either the validator
+    /// or `accept` returns `Err(message)`, the message is shown and the user
+    /// is asked again.
+    fn ask<T>(&self, accept: impl Fn(&str) -> Result<T, String>) -> io::Result<T> {
+        use ansi_term::{Colour, Style};
 
         let (tl, tr, bl, br, h, v) = get_border(self.style.as_deref().unwrap_or("plain"));
         let mut style = Style::new();
 
-        if let Some(ref color) = self.color {
-            if let Some(colour) = crate::components::label::parse_colour(color) {
-                style = style.fg(colour);
-            }
+        if let Some(ref color) = self.color
+            && let Some(colour) = crate::components::label::parse_colour(color)
+        {
+            style = style.fg(colour);
+        }
+        if let Some(ref color) = self.bg
+            && let Some(colour) = crate::components::label::parse_colour(color)
+        {
+            style = style.on(colour);
         }
         if self.bold {
             style = style.bold();
         }
 
         let padded_label = pad(&self.label, self.width);
-        let styled_label = style.paint(padded_label).to_string();
+        let styled_label = paint(&style, &padded_label);
 
         // Draw box
         println!("{}{}{}", tl, h.to_string().repeat(self.width), tr);
         println!("{}{}{}", v, styled_label, v);
         println!("{}{}{}", bl, h.to_string().repeat(self.width), br);
 
-        // Input line
-        print!("> ");
-        io::stdout().flush().unwrap();
+        let mut attempts = 0;
+        loop {
+            // Input line
+            print!("{}", paint(&style, &self.symbol));
+            io::stdout().flush()?;
 
-        let mut input = String::new();
+            let (mut input, eof) = self.read_answer()?;
+            if input.is_empty() {
+                input = self.default.clone().unwrap_or_default();
+            }
+
+            let checked = match self.validator {
+                Some(ref check) => check(&input),
+                None => Ok(()),
+            };
+            let message = match checked.and_then(|()| accept(&input)) {
+                Ok(answer) => return Ok(answer),
+                Err(message) => message,
+            };
+            println!("{}", paint(&Style::new().fg(Colour::Red), &message));
+
+            attempts += 1;
+            if eof {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, message));
+            }
+            if self.max_attempts.is_some_and(|max| attempts >= max) {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+            }
+        }
+    }
+
+    /// Reads one trimmed answer, along with whether standard input has been closed.
+    fn read_answer(&self) -> io::Result<(String, bool)> {
+        if let Some(mask) = self
+            .mask_char
+            .filter(|_| self.mask && io::stdin().is_terminal())
+        {
+            return read_masked(mask);
+        }
         if self.mask {
-            input = rpassword::read_password().unwrap_or_default();
-        } else {
-            io::stdin().read_line(&mut input).unwrap();
-            input = input.trim().to_string();
+            return match rpassword::read_password() {
+                Ok(password) => Ok((password, false)),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok((String::new(), true)),
+                Err(e) => Err(e),
+            };
         }
+        // A closed stdin reads zero bytes, unlike an empty line
+        let mut input = String::new();
+        let eof = io::stdin().read_line(&mut input)? == 0;
+        Ok((input.trim().to_string(), eof))
+    }
+}
 
-        if input.is_empty() {
-            self.default.clone().unwrap_or_default()
-        } else {
-            input
+/// Reads a line in raw mode, echoing `mask` for each character, along with
+/// whether the input was cancelled with Ctrl-C or Ctrl-D.
+fn read_masked(mask: char) -> io::Result<(String, bool)> {
+    /// Leaves raw mode when dropped, so every return path restores the terminal.
+    struct RawMode;
+
+    impl Drop for RawMode {
+        fn drop(&mut self) {
+            let _ = terminal::disable_raw_mode();
         }
     }
+
+    terminal::enable_raw_mode()?;
+    let raw = RawMode;
+
+    let mut input = String::new();
+    let mut stdout = io::stdout();
+    let cancelled = loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Enter => break false,
+            KeyCode::Char('c') if ctrl => break true,
+            KeyCode::Char('d') if ctrl && input.is_empty() => break true,
+            KeyCode::Backspace if input.pop().is_some() => write!(stdout, "\x08 \x08")?,
+            KeyCode::Char(c) if !ctrl => {
+                input.push(c);
+                write!(stdout, "{}", mask)?;
+            }
+            _ => {}
+        }
+        stdout.flush()?;
+    };
+
+    drop(raw);
+    println!();
+    if cancelled {
+        Ok((String::new(), true))
+    } else {
+        Ok((input, false))
+    }
 }
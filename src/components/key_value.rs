@@ -0,0 +1,246 @@
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::layout::{pad, visible_width, wrap_text};
+use crate::style::paint;
+use crate::theme::theme;
+use ansi_term::Style;
+
+/// A component for listing fields with their separators lined up.
+///
+/// The `KeyValue` struct prints one field per line, such as a config dump or
+/// version summary. Keys are padded to the display width of the widest key,
+/// so the separators and values line up even when keys contain wide
+/// characters. Values that span several lines, either through newlines or
+/// wrapping to a `width`, continue under the value column.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::KeyValue;
+///
+/// let fields = KeyValue::new()
+///     .pair("Name", "cliux")
+///     .pair("Version", "0.3")
+///     .render();
+///
+/// assert_eq!(fields, "Name    : cliux\nVersion : 0.3");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KeyValue {
+    pairs: Vec<(String, String)>,
+    separator: String,
+    key_color: Option<String>,
+    width: Option<usize>,
+}
+
+impl Default for KeyValue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyValue {
+    /// Creates a new, empty `KeyValue` instance.
+    ///
+    /// By default, keys and values are separated by `":"` and values are
+    /// not wrapped.
+    ///
+    /// # Returns
+    ///
+    /// A new `KeyValue` instance.
+    pub fn new() -> Self {
+        Self {
+            pairs: Vec::new(),
+            separator: ":".to_string(),
+            key_color: theme().accent_color,
+            width: None,
+        }
+    }
+
+    /// Adds a field to the listing.
+    ///
+    /// This method consumes `self` and returns a new `KeyValue` instance,
+    /// allowing for method chaining. Fields are shown in the order they are added.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the field.
+    /// * `value` - The value of the field. Newlines start continuation lines.
+    ///
+    /// # Returns
+    ///
+    /// The `KeyValue` instance with the field added.
+    pub fn pair(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.pairs.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the separator drawn between each key and its value.
+    ///
+    /// This method consumes `self` and returns a new `KeyValue` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - A string slice such as `":"`, `"="`, or `"→"`.
+    ///
+    /// # Returns
+    ///
+    /// The `KeyValue` instance with the updated separator.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the color of the keys.
+    ///
+    /// This method consumes `self` and returns a new `KeyValue` instance,
+    /// allowing for method chaining. Accepts the same colors as `Label::color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `KeyValue` instance with the updated key color.
+    pub fn key_color(mut self, color: &str) -> Self {
+        self.key_color = Some(color.to_string());
+        self
+    }
+
+    /// Sets the maximum width of each line, enabling value wrapping.
+    ///
+    /// This method consumes `self` and returns a new `KeyValue` instance,
+    /// allowing for method chaining. Wrapped lines continue under the
+    /// value column.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The maximum desired width for each line in characters.
+    ///
+    /// # Returns
+    ///
+    /// The `KeyValue` instance with the updated width setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::KeyValue;
+    ///
+    /// let fields = KeyValue::new()
+    ///     .pair("About", "Styled terminal output made simple")
+    ///     .width(24)
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     fields,
+    ///     "About : Styled terminal\n        output made\n        simple"
+    /// );
+    /// ```
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Renders the fields into a `String`.
+    ///
+    /// Lines are separated by newlines, with no trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered fields.
+    pub fn render(&self) -> String {
+        let key_width = self
+            .pairs
+            .iter()
+            .map(|(key, _)| visible_width(key))
+            .max()
+            .unwrap_or(0);
+        // Width of the padded key plus the separator and its surrounding spaces
+        let indent = key_width + visible_width(&self.separator) + 2;
+
+        let mut style = Style::new();
+        if let Some(colour) = self.key_color.as_deref().and_then(parse_colour) {
+            style = style.fg(colour);
+        }
+
+        let mut out = Vec::new();
+        for (key, value) in &self.pairs {
+            let lines: Vec<String> = match self.width {
+                Some(w) => wrap_text(value, w.saturating_sub(indent)),
+                None => value.lines().map(|l| l.to_string()).collect(),
+            };
+
+            // Pad the plain key first so the escape codes don't count towards the width
+            let head = format!(
+                "{} {} ",
+                paint(&style, &pad(key, key_width)),
+                self.separator
+            );
+            let mut lines = lines.into_iter();
+            out.push(format!("{}{}", head, lines.next().unwrap_or_default()));
+            for line in lines {
+                out.push(format!("{}{}", " ".repeat(indent), line));
+            }
+        }
+        out.join("\n")
+    }
+
+    /// Exports the fields as a JSON object.
+    ///
+    /// Keys keep the order they were added in. A key that repeats an earlier
+    /// one gets a numeric suffix (`"key"`, `"key_2"`, ...), so no value is
+    /// lost. No styling or wrapping is applied.
+    ///
+    /// Only available with the `serde` feature.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the JSON object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::KeyValue;
+    ///
+    /// let json = KeyValue::new()
+    ///     .pair("Name", "cliux")
+    ///     .pair("Version", "0.3")
+    ///     .to_json();
+    /// assert_eq!(json, r#"{"Name":"cliux","Version":"0.3"}"#);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        use crate::components::table::unique_keys;
+        use serde_json::{Map, Value};
+
+        let keys: Vec<String> = self.pairs.iter().map(|(key, _)| key.clone()).collect();
+        let object: Map<String, Value> = unique_keys(&keys)
+            .into_iter()
+            .zip(
+                self.pairs
+                    .iter()
+                    .map(|(_, value)| Value::String(value.clone())),
+            )
+            .collect();
+        Value::Object(object).to_string()
+    }
+
+    /// Prints the fields to the console.
+    pub fn print(&self) {
+        if !self.pairs.is_empty() {
+            println!("{}", self.render());
+        }
+    }
+}
+
+impl Render for KeyValue {
+    fn render(&self) -> String {
+        KeyValue::render(self)
+    }
+
+    fn print(&self) {
+        KeyValue::print(self)
+    }
+}
@@ -1,12 +1,18 @@
+use crate::components::Render;
+use crate::layout::display_width;
+use crate::style::{hyperlink, paint};
 use ansi_term::Colour;
+use std::fmt;
 
 /// Parses a string representation of a color into an `ansi_term::Colour` enum variant.
 ///
-/// This internal helper function supports a predefined set of color names (case-insensitive)
-/// and returns `None` if the name does not match any supported color.
+/// This internal helper function supports a predefined set of color names (case-insensitive),
+/// truecolor hex strings, and indices into the 256-color palette, and returns `None` if the
+/// name does not match any supported color.
 ///
 /// Supported colors include: "black", "red", "green", "yellow", "blue", "purple", "magenta", "cyan", "white".
-/// "purple" and "magenta" are treated as synonyms.
+/// "purple" and "magenta" are treated as synonyms. Hex strings such as `"#ff8800"` map to
+/// `Colour::RGB`, and `"208"` or `"color208"` map to `Colour::Fixed`.
 ///
 /// # Arguments
 ///
@@ -16,7 +22,8 @@ use ansi_term::Colour;
 ///
 /// An `Option<Colour>` which is `Some(Colour)` if the name is recognized, otherwise `None`.
 pub(crate) fn parse_colour(name: &str) -> Option<Colour> {
-    match name.to_lowercase().as_str() {
+    let name = name.trim().to_lowercase();
+    match name.as_str() {
         "black" => Some(Colour::Black),
         "red" => Some(Colour::Red),
         "green" => Some(Colour::Green),
@@ -25,7 +32,17 @@ pub(crate) fn parse_colour(name: &str) -> Option<Colour> {
         "purple" | "magenta" => Some(Colour::Purple),
         "cyan" => Some(Colour::Cyan),
         "white" => Some(Colour::White),
-        _ => None,
+        _ => {
+            if let Some(hex) = name.strip_prefix('#') {
+                if hex.len() != 6 || !hex.is_ascii() {
+                    return None;
+                }
+                let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+                return Some(Colour::RGB(channel(0)?, channel(2)?, channel(4)?));
+            }
+            let index = name.strip_prefix("color").unwrap_or(&name);
+            index.parse::<u8>().ok().map(Colour::Fixed)
+        }
     }
 }
 
@@ -62,7 +79,12 @@ pub(crate) fn parse_colour(name: &str) -> Option<Colour> {
 pub struct Label {
     text: String,
     color: Option<String>,
+    bg: Option<String>,
+    link: Option<String>,
     bold: bool,
+    italic: bool,
+    underline: bool,
+    dim: bool,
 }
 
 impl Label {
@@ -77,11 +99,16 @@ impl Label {
     /// # Returns
     ///
     /// A new `Label` instance.
-    pub fn new(text: &str) -> Self {
+    pub fn new(text: impl Into<String>) -> Self {
         Self {
-            text: text.to_string(),
+            text: text.into(),
             color: None,
+            bg: None,
+            link: None,
             bold: false,
+            italic: false,
+            underline: false,
+            dim: false,
         }
     }
 
@@ -89,7 +116,8 @@ impl Label {
     ///
     /// This method consumes `self` and returns a new `Label` instance,
     /// allowing for method chaining. The color name should be one of the
-    /// supported names (e.g., "red", "blue", "green"). If an unsupported
+    /// supported names (e.g., "red", "blue", "green"), a hex string such as
+    /// `"#ff8800"`, or a 256-color index such as `"208"`. If an unsupported
     /// color name is provided, the color will not be applied.
     ///
     /// # Arguments
@@ -104,6 +132,25 @@ impl Label {
         self
     }
 
+    /// Sets the background color of the label.
+    ///
+    /// This method consumes `self` and returns a new `Label` instance,
+    /// allowing for method chaining. Accepts the same color names, hex
+    /// strings, and 256-color indices as `color`. Like all styling, the
+    /// background is dropped when color output is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired background color.
+    ///
+    /// # Returns
+    ///
+    /// The `Label` instance with the updated background color.
+    pub fn bg(mut self, color: &str) -> Self {
+        self.bg = Some(color.to_string());
+        self
+    }
+
     /// Sets whether the label text should be bold.
     ///
     /// This method consumes `self` and returns a new `Label` instance,
@@ -121,6 +168,92 @@ impl Label {
         self
     }
 
+    /// Sets whether the label text should be italic.
+    ///
+    /// This method consumes `self` and returns a new `Label` instance,
+    /// allowing for method chaining. It combines with the other text styles,
+    /// such as `bold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `italic` - A boolean indicating whether the text should be italicized (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Label` instance with the updated italic setting.
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    /// Sets whether the label text should be underlined.
+    ///
+    /// This method consumes `self` and returns a new `Label` instance,
+    /// allowing for method chaining. It combines with the other text styles,
+    /// such as `bold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `underline` - A boolean indicating whether the text should be underlined (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Label` instance with the updated underline setting.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the label text should be dimmed.
+    ///
+    /// This method consumes `self` and returns a new `Label` instance,
+    /// allowing for method chaining. It combines with the other text styles,
+    /// such as `bold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - A boolean indicating whether the text should be dimmed (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Label` instance with the updated dim setting.
+    pub fn dim(mut self, dim: bool) -> Self {
+        self.dim = dim;
+        self
+    }
+
+    /// Makes the label a clickable hyperlink to `url`.
+    ///
+    /// This method consumes `self` and returns a new `Label` instance,
+    /// allowing for method chaining. The styled text is wrapped in an OSC 8
+    /// escape sequence, which supporting terminals display as a link. The
+    /// sequence is omitted when color output is disabled, so piped output
+    /// shows just the text.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The address the link points to.
+    ///
+    /// # Returns
+    ///
+    /// The `Label` instance with the link set.
+    ///
+    /// # Examples
+    ///
+    /// ```standalone_crate
+    /// use cliux::{ColorMode, Label, set_color_mode};
+    ///
+    /// set_color_mode(ColorMode::Always);
+    /// assert_eq!(
+    ///     Label::new("docs").link("https://example.com").inline(),
+    ///     "\x1b]8;;https://example.com\x1b\\[docs]\x1b]8;;\x1b\\"
+    /// );
+    /// ```
+    pub fn link(mut self, url: impl Into<String>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
+
     /// Applies a predefined style to the label.
     ///
     /// This method consumes `self` and returns a new `Label` instance,
@@ -162,19 +295,7 @@ impl Label {
     /// Label::new("Done").style("success").print(); // Prints "[Done]" in green
     /// ```
     pub fn print(&self) {
-        let mut style = ansi_term::Style::new();
-
-        if let Some(ref color_name) = self.color {
-            if let Some(colour) = parse_colour(color_name) {
-                style = style.fg(colour);
-            }
-        }
-
-        if self.bold {
-            style = style.bold();
-        }
-
-        println!("{}", style.paint(format!("[{}]", self.text)));
+        Render::print(self)
     }
 
     /// Returns the formatted label as an `ansi_term::ANSIGenericString`,
@@ -195,18 +316,85 @@ impl Label {
     /// println!("Operation Status: {}", status);
     /// ```
     pub fn inline(&self) -> String {
+        let styled = paint(&self.ansi_style(), &format!("[{}]", self.text));
+        match self.link {
+            Some(ref url) => hyperlink(url, &styled),
+            None => styled,
+        }
+    }
+
+    /// Returns the number of terminal columns the label occupies.
+    ///
+    /// Unlike the `len()` of `inline()`, this ignores ANSI escape codes and
+    /// counts the display width of the bracketed text, so it can be used to
+    /// line labels up in columns.
+    ///
+    /// # Returns
+    ///
+    /// The display width of `[TEXT]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Label;
+    ///
+    /// assert_eq!(Label::new("OK").color("green").visible_width(), 4);
+    /// assert_eq!(Label::new("完了").visible_width(), 6);
+    /// ```
+    pub fn visible_width(&self) -> usize {
+        display_width(&self.text) + 2
+    }
+
+    /// Builds the `ansi_term::Style` from the label's color and text style settings.
+    fn ansi_style(&self) -> ansi_term::Style {
         let mut style = ansi_term::Style::new();
 
-        if let Some(ref color_name) = self.color {
-            if let Some(colour) = parse_colour(color_name) {
-                style = style.fg(colour);
-            }
+        if let Some(ref color_name) = self.color
+            && let Some(colour) = parse_colour(color_name)
+        {
+            style = style.fg(colour);
+        }
+        if let Some(ref bg_name) = self.bg
+            && let Some(colour) = parse_colour(bg_name)
+        {
+            style = style.on(colour);
         }
 
         if self.bold {
             style = style.bold();
         }
+        if self.italic {
+            style = style.italic();
+        }
+        if self.underline {
+            style = style.underline();
+        }
+        if self.dim {
+            style = style.dimmed();
+        }
+        style
+    }
+}
+
+impl Render for Label {
+    fn render(&self) -> String {
+        self.inline()
+    }
+}
 
-        style.paint(format!("[{}]", self.text)).to_string()
+impl fmt::Display for Label {
+    /// Writes the same styled text as `inline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Label;
+    ///
+    /// let status = Label::new("OK").color("green");
+    /// println!("Status: {}", status);
+    /// assert_eq!(status.to_string(), status.inline());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.inline())
     }
 }
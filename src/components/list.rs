@@ -1,4 +1,9 @@
-use crate::layout::wrap_text;
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::layout::{truncate, visible_width, wrap_text};
+use crate::style::{paint, unicode_enabled};
+use crate::theme::{Theme, theme};
+use ansi_term::{Colour, Style};
 
 /// A component for displaying lists of items in the terminal.
 ///
@@ -35,9 +40,65 @@ use crate::layout::wrap_text;
 ///     .print();
 /// ```
 pub struct List {
-    items: Vec<String>,
+    items: Vec<Item>,
     bullet: Option<String>,
+    // Whether `bullet` is the built-in default, which changes with the nesting level
+    default_bullet: bool,
     width: Option<usize>,
+    max_width: Option<usize>,
+    continuation: Option<String>,
+    indent: usize,
+    hierarchical: bool,
+    color_marks: bool,
+    box_marks: bool,
+    bullet_color: Option<String>,
+    text_color: Option<String>,
+    number_format: NumberFormat,
+    start: usize,
+}
+
+/// The style of the numbers in a numbered `List`.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::{List, NumberFormat};
+///
+/// let steps = List::new(vec!["Fetch", "Build"]).number_format(NumberFormat::Paren).render();
+/// assert_eq!(steps, "1) Fetch\n2) Build");
+///
+/// let padded = List::new(vec!["Fetch", "Build"])
+///     .number_format(NumberFormat::Custom(Box::new(|n| format!("{:02}.", n))))
+///     .start_at(9)
+///     .render();
+/// assert_eq!(padded, "09. Fetch\n10. Build");
+/// ```
+#[derive(Default)]
+pub enum NumberFormat {
+    /// A number followed by a period, such as `1.` (the default).
+    #[default]
+    Dot,
+    /// A number followed by a closing parenthesis, such as `1)`.
+    Paren,
+    /// A number in square brackets, such as `[1]`.
+    Bracket,
+    /// A custom format, given each item's number and returning its marker.
+    /// In hierarchical lists it is given the number within the item's level.
+    Custom(Box<dyn Fn(usize) -> String>),
+}
+
+/// A single list entry with its nesting level and optional checkbox state.
+struct Item {
+    level: usize,
+    text: String,
+    checked: Option<bool>,
+}
+
+impl Default for List {
+    /// Creates an empty bullet list, ready for `item_at` or `extend`.
+    fn default() -> Self {
+        Self::new(Vec::<String>::new())
+    }
 }
 
 impl List {
@@ -49,23 +110,212 @@ impl List {
     ///
     /// # Arguments
     ///
-    /// * `items` - A `Vec` of string slices representing the items in the list.
+    /// * `items` - The items in the list, such as a `Vec` of string slices or of `String`s.
     ///
     /// # Returns
     ///
     /// A new `List` instance.
-    pub fn new(items: Vec<&str>) -> Self {
+    pub fn new(items: impl IntoIterator<Item: AsRef<str>>) -> Self {
+        let theme = theme();
+        // A bullet chosen by the theme is drawn as given, like one set with `bullet`
+        let default_bullet = theme.bullet == Theme::default().bullet;
         Self {
-            items: items.into_iter().map(|s| s.to_string()).collect(),
-            bullet: Some("•".to_string()),
+            items: items
+                .into_iter()
+                .map(|s| Item {
+                    level: 0,
+                    text: s.as_ref().to_string(),
+                    checked: None,
+                })
+                .collect(),
+            bullet: Some(theme.bullet),
+            default_bullet,
             width: None,
+            max_width: None,
+            continuation: None,
+            indent: 2,
+            hierarchical: false,
+            color_marks: true,
+            box_marks: false,
+            bullet_color: None,
+            text_color: None,
+            number_format: NumberFormat::Dot,
+            start: 1,
+        }
+    }
+
+    /// Creates a new task list, with a done or pending marker before each item.
+    ///
+    /// Checked items render as `[x] text` and unchecked items as `[ ] text`.
+    /// The `[x]` marker is colored green and `[ ]` gray, unless disabled with
+    /// `color_marks(false)` or by the `ColorMode`. Wrapped lines continue
+    /// past the marker.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The `(done, text)` pairs, such as a `Vec<(bool, &str)>`.
+    ///
+    /// # Returns
+    ///
+    /// A new `List` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::List;
+    ///
+    /// let todo = List::checklist(vec![(true, "Write docs"), (false, "Ship it")]).render();
+    /// assert_eq!(todo, "[x] Write docs\n[ ] Ship it");
+    /// ```
+    pub fn checklist<S: Into<String>>(items: impl IntoIterator<Item = (bool, S)>) -> Self {
+        Self {
+            items: items
+                .into_iter()
+                .map(|(done, text)| Item {
+                    level: 0,
+                    text: text.into(),
+                    checked: Some(done),
+                })
+                .collect(),
+            ..Self::default()
         }
     }
 
+    /// Sets whether checklist markers are colored.
+    ///
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining. When enabled (the default), checked
+    /// markers are green and unchecked markers gray.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A boolean indicating whether to color the markers (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance with the updated marker coloring.
+    pub fn color_marks(mut self, color: bool) -> Self {
+        self.color_marks = color;
+        self
+    }
+
+    /// Sets whether checklist markers are drawn as ballot boxes (`☑` and `☐`).
+    ///
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining. When Unicode is disabled with
+    /// `set_unicode(false)`, the `[x]` and `[ ]` markers are used instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `boxes` - A boolean indicating whether to use ballot boxes (`true`) or brackets (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance with the updated marker style.
+    pub fn box_marks(mut self, boxes: bool) -> Self {
+        self.box_marks = boxes;
+        self
+    }
+
+    /// Adds an item at the given nesting level.
+    ///
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining. Level 0 is the top level; each deeper
+    /// level is indented further (2 spaces by default, see `indent`). Items
+    /// are nested under the closest preceding item with a lower level.
+    ///
+    /// With the default bullet, each level cycles through `•`, `◦`, and `▪`
+    /// (or `*`, `-`, and `+` when Unicode is disabled). A custom bullet is
+    /// used at every level. Numbered lists restart their count at each
+    /// nested level.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The nesting depth of the item, starting at 0.
+    /// * `text` - The text of the item.
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance with the item added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::List;
+    ///
+    /// let outline = List::default()
+    ///     .item_at(0, "Fruit")
+    ///     .item_at(1, "Apple")
+    ///     .item_at(2, "Granny Smith")
+    ///     .item_at(0, "Vegetables")
+    ///     .render();
+    ///
+    /// assert_eq!(outline, "• Fruit\n  ◦ Apple\n    ▪ Granny Smith\n• Vegetables");
+    /// ```
+    pub fn item_at(mut self, level: usize, text: impl Into<String>) -> Self {
+        self.items.push(Item {
+            level,
+            text: text.into(),
+            checked: None,
+        });
+        self
+    }
+
+    /// Sets the number of spaces each nesting level is indented by.
+    ///
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining. The default is 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `spaces` - The number of spaces added per level.
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance with the updated indentation.
+    pub fn indent(mut self, spaces: usize) -> Self {
+        self.indent = spaces;
+        self
+    }
+
+    /// Configures the list to use hierarchical numbering (e.g., "1. ", "1.1. ").
+    ///
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining. Nested items are numbered with the
+    /// numbers of their parents as a prefix. Calling this will disable
+    /// custom bullets.
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance configured for hierarchical numbering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::List;
+    ///
+    /// let outline = List::default()
+    ///     .item_at(0, "Setup")
+    ///     .item_at(1, "Install")
+    ///     .item_at(1, "Configure")
+    ///     .item_at(0, "Usage")
+    ///     .hierarchical()
+    ///     .render();
+    ///
+    /// assert_eq!(outline, "1. Setup\n  1.1. Install\n  1.2. Configure\n2. Usage");
+    /// ```
+    pub fn hierarchical(mut self) -> Self {
+        self.bullet = None;
+        self.hierarchical = true;
+        self
+    }
+
     /// Sets a custom bullet symbol for the list.
     ///
     /// This method consumes `self` and returns a new `List` instance,
     /// allowing for method chaining. Calling this will disable numbering.
+    /// The symbol is drawn as given at every nesting level, even when it
+    /// matches the default bullet.
     ///
     /// # Arguments
     ///
@@ -74,8 +324,34 @@ impl List {
     /// # Returns
     ///
     /// The `List` instance with the updated bullet symbol.
-    pub fn bullet(mut self, symbol: &str) -> Self {
-        self.bullet = Some(symbol.to_string());
+    ///
+    /// # Examples
+    ///
+    /// Wrapped lines line up under the text, even after a wide emoji bullet:
+    /// ```
+    /// use cliux::List;
+    ///
+    /// let list = List::new(vec!["Ship the release notes today"])
+    ///     .bullet("👉")
+    ///     .width(18)
+    ///     .render();
+    /// assert_eq!(list, "👉 Ship the\n   release notes\n   today");
+    /// ```
+    ///
+    /// A bullet set explicitly is kept on nested items:
+    /// ```
+    /// use cliux::List;
+    ///
+    /// let outline = List::default()
+    ///     .bullet("•")
+    ///     .item_at(0, "Fruit")
+    ///     .item_at(1, "Apple")
+    ///     .render();
+    /// assert_eq!(outline, "• Fruit\n  • Apple");
+    /// ```
+    pub fn bullet(mut self, symbol: impl Into<String>) -> Self {
+        self.bullet = Some(symbol.into());
+        self.default_bullet = false;
         self
     }
 
@@ -89,6 +365,54 @@ impl List {
     /// The `List` instance configured for numbering.
     pub fn numbered(mut self) -> Self {
         self.bullet = None;
+        self.hierarchical = false;
+        self
+    }
+
+    /// Sets the style of the numbers, and switches the list to numbering.
+    ///
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining. Hierarchical numbering is kept, with
+    /// the full number such as `1.2` placed in the chosen format. Wrapped
+    /// lines line up with the text after the number, however wide it is.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The `NumberFormat` to use.
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance with the updated number format.
+    pub fn number_format(mut self, format: NumberFormat) -> Self {
+        self.bullet = None;
+        self.number_format = format;
+        self
+    }
+
+    /// Sets the number of the first item in a numbered list.
+    ///
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining. Every level of a hierarchical list
+    /// counts from this number. The default is 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number given to the first item.
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance with the updated starting number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::List;
+    ///
+    /// let list = List::new(vec!["Deploy", "Verify"]).numbered().start_at(4).render();
+    /// assert_eq!(list, "4. Deploy\n5. Verify");
+    /// ```
+    pub fn start_at(mut self, n: usize) -> Self {
+        self.start = n;
         self
     }
 
@@ -112,33 +436,309 @@ impl List {
         self
     }
 
-    /// Prints the formatted list to the console.
+    /// Sets the color of the bullets or numbers.
     ///
-    /// Each item is printed on its own line, prefixed by either a bullet
-    /// or a number, and potentially wrapped if a width is set.
-    pub fn print(&self) {
-        for (i, item) in self.items.iter().enumerate() {
-            let prefix = match &self.bullet {
-                Some(symbol) => format!("{} ", symbol),
-                None => format!("{}. ", i + 1),
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining. Only the bullet or number is colored,
+    /// not the space after it or the item text. Checklist markers keep their
+    /// own colors. Accepts the same colors as `Label::color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance with the updated bullet color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::List;
+    ///
+    /// List::new(vec!["Fetch", "Build", "Deploy"])
+    ///     .numbered()
+    ///     .bullet_color("cyan")
+    ///     .print();
+    /// ```
+    pub fn bullet_color(mut self, color: &str) -> Self {
+        self.bullet_color = Some(color.to_string());
+        self
+    }
+
+    /// Sets the color of the item text.
+    ///
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining. Accepts the same colors as `Label::color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance with the updated text color.
+    pub fn text_color(mut self, color: &str) -> Self {
+        self.text_color = Some(color.to_string());
+        self
+    }
+
+    /// Sets the maximum width of each item, truncating longer items instead of wrapping them.
+    ///
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining. Every item stays on one line, cut with
+    /// `…` so that it fits together with its indent and bullet or number.
+    /// When the prefix alone is too wide, the prefix is still shown followed
+    /// by `…`. If `width` is also set, wrapping takes precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The maximum width of each line in characters.
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance with the updated maximum width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::List;
+    ///
+    /// let list = List::new(vec!["Short", "A much longer item"]).bullet("-").max_width(12).render();
+    /// assert_eq!(list, "- Short\n- A much lo…");
+    ///
+    /// // Too narrow for any text
+    /// assert_eq!(List::new(vec!["Item"]).numbered().max_width(2).render(), "1. …");
+    /// ```
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Sets the prefix used on the continuation lines of a wrapped item.
+    ///
+    /// By default, continuation lines are indented with spaces matching the
+    /// width of the bullet or number. A custom prefix such as `"│ "` or
+    /// `"  ↳ "` can be used instead for quoted or threaded lists. The wrap
+    /// width accounts for whichever prefix is wider, so every line still fits.
+    ///
+    /// This method consumes `self` and returns a new `List` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - A string slice used at the start of each continuation line.
+    ///
+    /// # Returns
+    ///
+    /// The `List` instance with the updated continuation prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::List;
+    ///
+    /// List::new(vec!["A long reply that wraps across several lines of the thread."])
+    ///     .bullet(">")
+    ///     .continuation("│ ")
+    ///     .width(30)
+    ///     .print();
+    /// ```
+    pub fn continuation(mut self, prefix: impl Into<String>) -> Self {
+        self.continuation = Some(prefix.into());
+        self
+    }
+
+    /// Renders the formatted list into a `String`.
+    ///
+    /// The output is identical to what `print` writes, with lines separated
+    /// by newlines and no trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::List;
+    ///
+    /// let list = List::new(vec!["One", "Two"]).numbered().render();
+    /// assert_eq!(list, "1. One\n2. Two");
+    /// ```
+    pub fn render(&self) -> String {
+        let style_for = |color: &Option<String>| {
+            color
+                .as_deref()
+                .and_then(parse_colour)
+                .map_or_else(Style::new, |colour| colour.normal())
+        };
+        let bullet_style = style_for(&self.bullet_color);
+        let text_style = style_for(&self.text_color);
+
+        let mut out = Vec::new();
+        // The running count at each level, from the top level down to the current item
+        let mut counters: Vec<usize> = Vec::new();
+        for Item {
+            level,
+            text: item,
+            checked,
+        } in &self.items
+        {
+            let level = *level;
+            counters.truncate(level + 1);
+            while counters.len() <= level {
+                counters.push(0);
+            }
+            counters[level] += 1;
+
+            let prefix = match (checked, &self.bullet) {
+                (Some(done), _) => self.check_mark(*done),
+                (None, Some(_)) if self.default_bullet => {
+                    let bullets = if unicode_enabled() {
+                        ["•", "◦", "▪"]
+                    } else {
+                        ["*", "-", "+"]
+                    };
+                    format!("{} ", bullets[level % bullets.len()])
+                }
+                (None, Some(symbol)) => format!("{} ", symbol),
+                (None, None) => format!("{} ", self.number_marker(&counters)),
             };
+            // Markers may be painted, multi-byte, or wide, so measure their visible width rather than their length
+            let prefix_width = visible_width(&prefix);
+
+            let lead = " ".repeat(level * self.indent);
+            let continuation = self
+                .continuation
+                .clone()
+                .unwrap_or_else(|| " ".repeat(prefix_width));
 
             let lines = if let Some(w) = self.width {
-                // Subtract prefix length from total width for wrapping calculation
-                wrap_text(item, w.saturating_sub(prefix.len()))
+                // Subtract the wider of the two prefixes from total width for wrapping calculation
+                let indent = prefix_width.max(visible_width(&continuation));
+                wrap_text(item, w.saturating_sub(lead.len() + indent))
+            } else if let Some(max) = self.max_width {
+                // Keep at least the ellipsis when the prefix fills the line
+                let room = max.saturating_sub(lead.len() + prefix_width).max(1);
+                vec![truncate(item, room)]
             } else {
                 vec![item.clone()]
             };
 
+            // Checklist markers are already painted
+            let prefix = if checked.is_some() {
+                prefix
+            } else {
+                format!("{} ", paint(&bullet_style, prefix.trim_end()))
+            };
+
             for (j, line) in lines.iter().enumerate() {
+                let line = paint(&text_style, line);
                 if j == 0 {
                     // First line gets the prefix
-                    println!("{}{}", prefix, line);
+                    out.push(format!("{}{}{}", lead, prefix, line));
                 } else {
-                    // Subsequent lines are indented by the prefix length
-                    println!("{}{}", " ".repeat(prefix.len()), line);
+                    // Subsequent lines get the continuation prefix
+                    out.push(format!("{}{}{}", lead, continuation, line));
                 }
             }
         }
+        out.join("\n")
+    }
+
+    /// Formats the number of the item whose running counts, from the top level down, are `counters`.
+    fn number_marker(&self, counters: &[usize]) -> String {
+        // Counters start at 1; skipped levels also count as 1, so they show the starting number
+        let number = |n: usize| n.max(1) - 1 + self.start;
+        let last = counters.last().copied().unwrap_or(1);
+        let text = if self.hierarchical {
+            let parts: Vec<String> = counters.iter().map(|&n| number(n).to_string()).collect();
+            parts.join(".")
+        } else {
+            number(last).to_string()
+        };
+        match self.number_format {
+            NumberFormat::Dot => format!("{}.", text),
+            NumberFormat::Paren => format!("{})", text),
+            NumberFormat::Bracket => format!("[{}]", text),
+            NumberFormat::Custom(ref format) => format(number(last)),
+        }
+    }
+
+    /// Builds the painted checkbox marker for a checklist item, with its trailing space.
+    fn check_mark(&self, done: bool) -> String {
+        let mark = match (self.box_marks && unicode_enabled(), done) {
+            (true, true) => "☑",
+            (true, false) => "☐",
+            (false, true) => "[x]",
+            (false, false) => "[ ]",
+        };
+        let style = match (self.color_marks, done) {
+            (false, _) => Style::new(),
+            (true, true) => Colour::Green.normal(),
+            (true, false) => Colour::Fixed(8).normal(),
+        };
+        format!("{} ", paint(&style, mark))
+    }
+
+    /// Prints the formatted list to the console.
+    ///
+    /// Each item is printed on its own line, prefixed by either a bullet
+    /// or a number, and potentially wrapped if a width is set.
+    pub fn print(&self) {
+        if !self.items.is_empty() {
+            println!("{}", self.render());
+        }
+    }
+}
+
+impl Render for List {
+    fn render(&self) -> String {
+        List::render(self)
+    }
+
+    fn print(&self) {
+        List::print(self)
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for List {
+    /// Creates a bullet list from any iterator of strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::List;
+    ///
+    /// let files = vec!["a.txt".to_string(), "b.txt".to_string()];
+    /// let list: List = files.iter().collect();
+    /// assert_eq!(list.render(), "• a.txt\n• b.txt");
+    /// ```
+    fn from_iter<I: IntoIterator<Item = S>>(items: I) -> Self {
+        let mut list = Self::default();
+        list.extend(items);
+        list
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for List {
+    /// Appends top-level items from any iterator of strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::List;
+    ///
+    /// let mut list = List::new(vec!["Build"]);
+    /// list.extend(["Test", "Deploy"]);
+    /// assert_eq!(list.render(), "• Build\n• Test\n• Deploy");
+    /// ```
+    fn extend<I: IntoIterator<Item = S>>(&mut self, items: I) {
+        self.items.extend(items.into_iter().map(|s| Item {
+            level: 0,
+            text: s.as_ref().to_string(),
+            checked: None,
+        }));
     }
 }
@@ -1,21 +1,88 @@
+pub mod alert;
+pub mod badge;
+pub mod banner;
+mod banner_font;
 pub mod boxed;
 pub mod confirm;
 pub mod divider;
 pub mod input;
+pub mod key_value;
 pub mod label;
 pub mod list;
+pub mod multiselect;
 pub mod note;
+pub mod progress;
 pub mod section;
+pub mod select;
+pub mod sparkline;
+#[cfg(feature = "tokio")]
+pub mod spinner;
 pub mod table;
 pub mod tag;
+pub mod timeline;
+pub mod tree;
 
+pub use alert::Alert;
+pub use badge::Badge;
+pub use banner::Banner;
 pub use boxed::Boxed;
 pub use confirm::Confirm;
 pub use divider::Divider;
 pub use input::Input;
+pub use key_value::KeyValue;
 pub use label::Label;
 pub use list::List;
+pub use multiselect::MultiSelect;
 pub use note::Note;
+pub use progress::ProgressBar;
 pub use section::Section;
-pub use table::Table;
+pub use select::Select;
+pub use sparkline::Sparkline;
+#[cfg(feature = "tokio")]
+pub use spinner::{AsyncSpinner, SpinnerGuard};
+pub use table::{Table, TableRow};
 pub use tag::Tag;
+pub use timeline::Timeline;
+pub use tree::{Tree, TreeNode};
+
+/// A component that can be rendered to a `String`.
+///
+/// Every visual component implements `Render`, so different components can
+/// be collected together, for example as `Vec<Box<dyn Render>>` in a
+/// `Document`, or handled by generic code. Implementing `render` for your
+/// own type is enough to use it alongside the built-in components.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::{Document, Label, Note, Render};
+///
+/// struct Greeting(&'static str);
+///
+/// impl Render for Greeting {
+///     fn render(&self) -> String {
+///         format!("Hello, {}!", self.0)
+///     }
+/// }
+///
+/// let parts: Vec<Box<dyn Render>> = vec![
+///     Box::new(Greeting("world")),
+///     Box::new(Label::new("OK")),
+///     Box::new(Note::new("Saved").style("+").width(9)),
+/// ];
+/// let output: Vec<String> = parts.iter().map(|part| part.render()).collect();
+/// assert_eq!(output[0], "Hello, world!");
+/// assert_eq!(output[1], "[OK]");
+///
+/// let page = Document::new().add(Greeting("cliux")).spacing(0).render();
+/// assert_eq!(page, "Hello, cliux!");
+/// ```
+pub trait Render {
+    /// Renders the component into a `String`, with no trailing newline.
+    fn render(&self) -> String;
+
+    /// Prints the rendered component to the console, followed by a newline.
+    fn print(&self) {
+        println!("{}", self.render());
+    }
+}
@@ -0,0 +1,345 @@
+use crate::components::note::get_border;
+use crate::layout::{default_width, pad};
+use crate::style::paint;
+use crate::theme::theme;
+use ansi_term::Style;
+use std::io::{self, Write};
+
+/// An interactive prompt for picking several options from a checklist.
+///
+/// `MultiSelect` draws a boxed prompt in the same way as `Confirm`, lists the
+/// options numbered from 1 with `[x]` next to those selected by default, and
+/// reads the choices from standard input as numbers separated by commas or
+/// spaces (e.g. `1,3 4`). Invalid input prints a hint and asks again.
+///
+/// # Examples
+///
+/// ```no_run
+/// use cliux::MultiSelect;
+///
+/// let features = ["logging", "metrics", "tracing", "alerts"];
+/// let chosen = MultiSelect::new("Features")
+///     .options(&features)
+///     .defaults(&[0, 2])
+///     .color("cyan")
+///     .prompt();
+///
+/// for i in chosen {
+///     println!("Enabled {}", features[i]);
+/// }
+/// ```
+pub struct MultiSelect {
+    label: String,
+    options: Vec<String>,
+    defaults: Vec<usize>,
+    color: Option<String>,
+    bold: bool,
+    style: Option<String>,
+    width: usize,
+    symbol: String,
+    max_attempts: Option<usize>,
+}
+
+impl MultiSelect {
+    /// Creates a new `MultiSelect` prompt with the given label and no options.
+    ///
+    /// By default, the prompt has "square" borders, a width of 40
+    /// characters (see `set_default_width`), and no options selected by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The question displayed at the top of the box.
+    ///
+    /// # Returns
+    ///
+    /// A new `MultiSelect` instance.
+    pub fn new(label: impl Into<String>) -> Self {
+        let theme = theme();
+        Self {
+            label: label.into(),
+            options: Vec::new(),
+            defaults: Vec::new(),
+            color: theme.accent_color,
+            bold: false,
+            style: Some(theme.border_style.unwrap_or_else(|| "square".to_string())),
+            // The borders are drawn outside `width`
+            width: default_width().map(|w| w.saturating_sub(2)).unwrap_or(40),
+            symbol: "> ".to_string(),
+            max_attempts: None,
+        }
+    }
+
+    /// Sets the options to choose from, in display order.
+    ///
+    /// This method consumes `self` and returns a new `MultiSelect` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The option labels, such as a slice of string slices or a `Vec<String>`.
+    ///
+    /// # Returns
+    ///
+    /// The `MultiSelect` instance with the options set.
+    pub fn options(mut self, options: impl IntoIterator<Item: AsRef<str>>) -> Self {
+        self.options = options
+            .into_iter()
+            .map(|o| o.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Sets the options that are selected when the user submits an empty line.
+    ///
+    /// This method consumes `self` and returns a new `MultiSelect` instance,
+    /// allowing for method chaining. Default options are marked with `[x]`
+    /// in the menu. Without defaults, an empty line selects nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - A slice of zero-based indices of the default options.
+    ///
+    /// # Returns
+    ///
+    /// The `MultiSelect` instance with the default options set.
+    pub fn defaults(mut self, indices: &[usize]) -> Self {
+        self.defaults = indices.to_vec();
+        self
+    }
+
+    /// Sets the color of the label and options.
+    ///
+    /// This method consumes `self` and returns a new `MultiSelect` instance,
+    /// allowing for method chaining. Accepts the same colors as `Confirm`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `MultiSelect` instance with the updated color.
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Sets whether the label and options should be bold.
+    ///
+    /// This method consumes `self` and returns a new `MultiSelect` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `bold` - A boolean indicating whether the text should be bold (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `MultiSelect` instance with the updated bold setting.
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    /// Sets the border style of the prompt box.
+    ///
+    /// This method consumes `self` and returns a new `MultiSelect` instance,
+    /// allowing for method chaining. Supports the same styles as `Note`:
+    /// `"rounded"`, `"square"` (default), and `"+"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - A string slice representing the desired border style.
+    ///
+    /// # Returns
+    ///
+    /// The `MultiSelect` instance with the updated border style.
+    pub fn style(mut self, style: &str) -> Self {
+        self.style = Some(style.to_string());
+        self
+    }
+
+    /// Sets the interior width of the prompt box.
+    ///
+    /// This method consumes `self` and returns a new `MultiSelect` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The desired width between the borders in characters.
+    ///
+    /// # Returns
+    ///
+    /// The `MultiSelect` instance with the updated width.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the symbol printed before the cursor on the input line.
+    ///
+    /// The default is `"> "`. The symbol is painted with the same color and
+    /// boldness as the label, and the cursor is left directly after it.
+    ///
+    /// This method consumes `self` and returns a new `MultiSelect` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The string slice to print before the cursor (e.g. `"❯ "`, `"$ "`).
+    ///
+    /// # Returns
+    ///
+    /// The `MultiSelect` instance with the updated prompt symbol.
+    pub fn prompt_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = symbol.into();
+        self
+    }
+
+    /// Limits how many times an invalid answer is asked again.
+    ///
+    /// By default the prompt keeps asking until it gets a valid answer.
+    ///
+    /// This method consumes `self` and returns a new `MultiSelect` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempts` - The maximum number of answers to read, including the first.
+    ///
+    /// # Returns
+    ///
+    /// The `MultiSelect` instance with the updated attempt limit.
+    pub fn max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Shows the menu and waits for the user to pick any number of options.
+    ///
+    /// This is `try_prompt` for callers that can't recover from I/O errors.
+    /// When every attempt allowed by `max_attempts` was invalid, the
+    /// defaults are returned.
+    ///
+    /// # Returns
+    ///
+    /// The zero-based indices of the chosen options, sorted and without duplicates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if reading from standard input or writing to standard output fails.
+    pub fn prompt(&self) -> Vec<usize> {
+        match self.try_prompt() {
+            Ok(indices) => indices,
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput => self.valid_defaults(),
+            Err(e) => panic!("failed to read input: {}", e),
+        }
+    }
+
+    /// Shows the menu and waits for the user to pick any number of options,
+    /// returning any I/O error.
+    ///
+    /// The user enters option numbers (starting at 1) separated by commas
+    /// and/or spaces. An empty line keeps the defaults, and so does closing
+    /// standard input (EOF). If any number is out of range or not a number,
+    /// a hint is printed and the user is asked again, up to the limit set by
+    /// `max_attempts`.
+    ///
+    /// # Returns
+    ///
+    /// The zero-based indices of the chosen options, sorted and without duplicates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from standard input or flushing standard
+    /// output fails. If every attempt allowed by `max_attempts` is invalid,
+    /// an `InvalidInput` error is returned.
+    pub fn try_prompt(&self) -> io::Result<Vec<usize>> {
+        let (tl, tr, bl, br, h, v) = get_border(self.style.as_deref().unwrap_or("plain"));
+        let mut style = Style::new();
+
+        if let Some(ref color) = self.color
+            && let Some(colour) = crate::components::label::parse_colour(color)
+        {
+            style = style.fg(colour);
+        }
+        if self.bold {
+            style = style.bold();
+        }
+
+        // Draw box
+        println!("{}{}{}", tl, h.repeat(self.width), tr);
+        println!("{}{}{}", v, paint(&style, &pad(&self.label, self.width)), v);
+        for (i, option) in self.options.iter().enumerate() {
+            let marker = if self.defaults.contains(&i) {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            let line = pad(&format!("  {} {}. {}", marker, i + 1, option), self.width);
+            println!("{}{}{}", v, paint(&style, &line), v);
+        }
+        println!("{}{}{}", bl, h.repeat(self.width), br);
+
+        let mut attempts = 0;
+        loop {
+            // Input line
+            print!("{}", paint(&style, &self.symbol));
+            io::stdout().flush()?;
+
+            // A closed stdin reads zero bytes, unlike an empty line
+            let mut input = String::new();
+            if io::stdin().read_line(&mut input)? == 0 {
+                println!();
+                return Ok(self.valid_defaults());
+            }
+
+            if let Some(indices) = self.parse_choices(input.trim()) {
+                return Ok(indices);
+            }
+
+            attempts += 1;
+            if self.max_attempts.is_some_and(|max| attempts >= max) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("no valid answer after {} attempts", attempts),
+                ));
+            }
+            println!(
+                "Invalid input. Please enter numbers from 1 to {}, separated by commas.",
+                self.options.len()
+            );
+        }
+    }
+
+    /// Returns the default options that are within range, sorted and without duplicates.
+    fn valid_defaults(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self
+            .defaults
+            .iter()
+            .copied()
+            .filter(|&i| i < self.options.len())
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+
+    /// Maps the user's answer to sorted option indices, or `None` if any entry is invalid.
+    fn parse_choices(&self, input: &str) -> Option<Vec<usize>> {
+        if input.is_empty() {
+            return Some(self.valid_defaults());
+        }
+        let mut indices: Vec<usize> = input
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match entry.parse::<usize>() {
+                Ok(n) if (1..=self.options.len()).contains(&n) => Some(n - 1),
+                _ => None,
+            })
+            .collect::<Option<_>>()?;
+        indices.sort_unstable();
+        indices.dedup();
+        Some(indices)
+    }
+}
@@ -1,5 +1,116 @@
-use crate::layout::pad;
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::layout::{
+    Align, default_width, hard_wrap, pad_align, terminal_width, truncate, visible_width,
+};
+use crate::style::{paint, stdout_is_terminal, unicode_enabled};
+use crate::theme::theme;
 use ansi_term::{Colour, Style};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{LazyLock, Mutex};
+
+/// The severity of a `Note`, ordered from least to most severe.
+///
+/// Each built-in kind maps to a level, and notes below the threshold set by
+/// `set_min_note_level` are not printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NoteLevel {
+    Debug,
+    Info,
+    Tip,
+    Warning,
+    Error,
+}
+
+impl NoteLevel {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => NoteLevel::Debug,
+            1 => NoteLevel::Info,
+            2 => NoteLevel::Tip,
+            3 => NoteLevel::Warning,
+            _ => NoteLevel::Error,
+        }
+    }
+}
+
+static MIN_NOTE_LEVEL: AtomicU8 = AtomicU8::new(NoteLevel::Debug as u8);
+
+/// Sets the minimum severity a `Note` must have to be printed.
+///
+/// Notes below this level become a no-op when `print` is called. This lets a
+/// `--quiet` or `--verbose` flag control which notes appear in one place.
+/// The default threshold is `NoteLevel::Debug`, so every note is shown.
+///
+/// # Arguments
+///
+/// * `level` - The lowest `NoteLevel` that should still be printed.
+///
+/// # Examples
+///
+/// ```standalone_crate
+/// use cliux::{Note, NoteLevel, set_min_note_level};
+///
+/// set_min_note_level(NoteLevel::Warning);
+/// Note::new("Only shown with --verbose").kind("tip").print(); // prints nothing
+/// Note::new("Disk almost full").kind("warning").print();
+/// set_min_note_level(NoteLevel::Debug);
+/// ```
+pub fn set_min_note_level(level: NoteLevel) {
+    MIN_NOTE_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Returns the minimum severity currently required for a `Note` to be printed.
+pub fn min_note_level() -> NoteLevel {
+    NoteLevel::from_u8(MIN_NOTE_LEVEL.load(Ordering::Relaxed))
+}
+
+/// The styling applied by a custom `Note` kind.
+///
+/// Register a kind with `register_note_kind` to use it with `Note::kind`
+/// alongside the built-in kinds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteKind {
+    /// The icon shown before the note's text.
+    pub icon: &'static str,
+    /// The color of the note's text and icon, as accepted by `Note::color`.
+    pub color: &'static str,
+    /// Whether the note's text is bold.
+    pub bold: bool,
+}
+
+static NOTE_KINDS: LazyLock<Mutex<HashMap<String, NoteKind>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Registers a custom kind for `Note::kind`.
+///
+/// This lets a CLI define its own vocabulary of callouts, such as
+/// `"security"` or `"deprecated"`, once at startup. Built-in kinds
+/// ("debug", "info", "tip", "warning", "error") always take precedence and
+/// can't be replaced. Notes of a custom kind have the level `NoteLevel::Info`.
+/// Registering the same name again replaces the earlier preset.
+///
+/// # Arguments
+///
+/// * `name` - The name passed to `Note::kind`.
+/// * `kind` - The `NoteKind` styling to apply.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::{Note, NoteKind, register_note_kind};
+///
+/// register_note_kind("security", NoteKind { icon: "🔒", color: "red", bold: true });
+///
+/// let note = Note::new("Token rotated").kind("security").style("+").width(24).render();
+/// assert_eq!(note, "+----------------------+\n| 🔒 Token rotated     |\n+----------------------+");
+/// ```
+pub fn register_note_kind(name: &str, kind: NoteKind) {
+    if let Ok(mut kinds) = NOTE_KINDS.lock() {
+        kinds.insert(name.to_string(), kind);
+    }
+}
 
 /// A styled callout block for warnings, tips, and info messages.
 ///
@@ -44,16 +155,30 @@ pub struct Note {
     text: String,
     icon: Option<String>,
     color: Option<String>,
+    bg: Option<String>,
     bold: bool,
+    italic: bool,
+    underline: bool,
+    dim: bool,
     style: String, // "rounded", "square", "+"
     width: usize,
+    level: NoteLevel,
+    kind: Option<String>,
+    plain_when_piped: bool,
+    truncate: bool,
+    padding: (usize, usize),
+    title: Option<String>,
+    messages: Vec<String>,
+    shadow: bool,
+    footer: Option<String>,
+    footer_align: Align,
 }
 
 impl Note {
     /// Creates a new `Note` instance with the given text.
     ///
-    /// By default, the note will have "rounded" borders, a width of 50 characters,
-    /// no specific icon, color, or boldness.
+    /// By default, the note will have "rounded" borders, a width of 50 characters
+    /// (see `set_default_width`), no specific icon, color, or boldness, and a level of `NoteLevel::Info`.
     ///
     /// # Arguments
     ///
@@ -62,14 +187,30 @@ impl Note {
     /// # Returns
     ///
     /// A new `Note` instance.
-    pub fn new(text: &str) -> Self {
+    pub fn new(text: impl Into<String>) -> Self {
         Self {
-            text: text.to_string(),
+            text: text.into(),
             icon: None,
             color: None,
+            bg: None,
             bold: false,
-            style: "rounded".to_string(),
-            width: 50,
+            italic: false,
+            underline: false,
+            dim: false,
+            style: theme()
+                .border_style
+                .unwrap_or_else(|| "rounded".to_string()),
+            width: default_width().unwrap_or(50),
+            level: NoteLevel::Info,
+            kind: None,
+            plain_when_piped: false,
+            truncate: false,
+            padding: (0, 0),
+            shadow: false,
+            footer: None,
+            footer_align: Align::Left,
+            title: None,
+            messages: Vec::new(),
         }
     }
 
@@ -77,14 +218,18 @@ impl Note {
     ///
     /// This method consumes `self` and returns a new `Note` instance,
     /// allowing for method chaining. It sets a default icon, color,
-    /// and boldness based on the `kind`.
+    /// boldness, and severity level based on the `kind`.
     ///
     /// Supported `kind`s:
+    /// - `"debug"`: Sets icon to "🔍", color to cyan.
     /// - `"info"`: Sets icon to "ℹ️", color to blue.
-    /// - `"warning"`: Sets icon to "⚠️", color to yellow, and text to bold.
     /// - `"tip"`: Sets icon to "💡", color to green.
+    /// - `"warning"`: Sets icon to "⚠️", color to yellow, and text to bold.
+    /// - `"error"`: Sets icon to "❌", color to red, and text to bold.
     ///
-    /// If an unknown `kind` is provided, the note remains unchanged.
+    /// Kinds added with `register_note_kind` apply their registered icon,
+    /// color, and boldness. If an unknown `kind` is provided, the note keeps
+    /// its styling and is treated as `NoteLevel::Info`.
     ///
     /// # Arguments
     ///
@@ -94,25 +239,36 @@ impl Note {
     ///
     /// The `Note` instance with the applied kind style.
     pub fn kind(mut self, kind: &str) -> Self {
-        match kind {
-            "info" => {
-                self.icon = Some("ℹ️".to_string());
-                self.color = Some("blue".to_string());
+        self.kind = Some(kind.to_string());
+        match kind_preset(kind) {
+            Some((icon, color, bold, level)) => {
+                self.icon = Some(icon.to_string());
+                self.color = Some(color);
+                self.bold = self.bold || bold;
+                self.level = level;
             }
-            "warning" => {
-                self.icon = Some("⚠️".to_string());
-                self.color = Some("yellow".to_string());
-                self.bold = true;
-            }
-            "tip" => {
-                self.icon = Some("💡".to_string());
-                self.color = Some("green".to_string());
-            }
-            _ => {}
+            None => self.level = NoteLevel::Info,
         }
         self
     }
 
+    /// Sets the severity level of the note explicitly.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining. This will override the level set by `kind()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The `NoteLevel` compared against `set_min_note_level` when printing.
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the updated level.
+    pub fn level(mut self, level: NoteLevel) -> Self {
+        self.level = level;
+        self
+    }
+
     /// Sets a custom icon for the note.
     ///
     /// This method consumes `self` and returns a new `Note` instance,
@@ -125,8 +281,8 @@ impl Note {
     /// # Returns
     ///
     /// The `Note` instance with the updated icon.
-    pub fn icon(mut self, icon: &str) -> Self {
-        self.icon = Some(icon.to_string());
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
         self
     }
 
@@ -134,7 +290,8 @@ impl Note {
     ///
     /// This method consumes `self` and returns a new `Note` instance,
     /// allowing for method chaining. Supported colors include "red", "green",
-    /// "yellow", "blue", "magenta" (or "purple"), "cyan", and "white".
+    /// "yellow", "blue", "magenta" (or "purple"), "cyan", and "white", as well
+    /// as hex strings like `"#ff8800"` and 256-color indices like `"208"`.
     /// Color names are case-insensitive.
     ///
     /// # Arguments
@@ -149,6 +306,26 @@ impl Note {
         self
     }
 
+    /// Sets the background color of the note.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining. Accepts the same color names, hex
+    /// strings, and 256-color indices as `color`. The background fills
+    /// the whole interior between the borders, not just the text, and is
+    /// dropped when color output is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired background color.
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the updated background color.
+    pub fn bg(mut self, color: &str) -> Self {
+        self.bg = Some(color.to_string());
+        self
+    }
+
     /// Sets whether the note's text and icon should be bold.
     ///
     /// This method consumes `self` and returns a new `Note` instance,
@@ -166,6 +343,60 @@ impl Note {
         self
     }
 
+    /// Sets whether the note's text and icon should be italic.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining. It combines with the other text styles,
+    /// such as `bold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `italic` - A boolean indicating whether the text should be italicized (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the updated italic setting.
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    /// Sets whether the note's text and icon should be underlined.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining. It combines with the other text styles,
+    /// such as `bold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `underline` - A boolean indicating whether the text should be underlined (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the updated underline setting.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the note's text and icon should be dimmed.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining. It combines with the other text styles,
+    /// such as `bold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - A boolean indicating whether the text should be dimmed (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the updated dim setting.
+    pub fn dim(mut self, dim: bool) -> Self {
+        self.dim = dim;
+        self
+    }
+
     /// Sets the border style for the note.
     ///
     /// This method consumes `self` and returns a new `Note` instance,
@@ -208,64 +439,615 @@ impl Note {
         self
     }
 
-    /// Prints the formatted note to the console.
+    /// Sizes the note to span the full width of the terminal.
     ///
-    /// This method constructs the note with its borders, icon, styled text,
-    /// and padding, then prints it to standard output.
-    pub fn print(&self) {
-        let (tl, tr, bl, br, _, v) = get_border(&self.style);
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining. The width is read from the terminal
+    /// when this method is called. When standard output isn't a
+    /// terminal, the default width is used instead: 50, or the width set
+    /// with `set_default_width`.
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with its width set to the terminal width.
+    pub fn full_width(mut self) -> Self {
+        self.width = terminal_width().or_else(default_width).unwrap_or(50);
+        self
+    }
+
+    /// Sets a bold heading shown above the note's text.
+    ///
+    /// With a title, the first interior line holds the icon and the title in
+    /// bold, and the text follows as the body, wrapped across as many
+    /// interior lines as it needs. A title too wide for the note wraps too,
+    /// unless `truncate` is enabled. Every line is padded to the interior
+    /// width so the borders stay aligned.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The string slice to display as the heading.
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the title set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Note;
+    ///
+    /// let note = Note::new("Run the migration before upgrading.")
+    ///     .title("Heads up")
+    ///     .style("+")
+    ///     .width(24)
+    ///     .render();
+    /// assert_eq!(
+    ///     note,
+    ///     "+----------------------+\n\
+    ///      | Heads up             |\n\
+    ///      | Run the migration    |\n\
+    ///      | before upgrading.    |\n\
+    ///      +----------------------+"
+    /// );
+    ///
+    /// let note = Note::new("Done.").title("Database migration required").style("+").width(16).render();
+    /// assert_eq!(
+    ///     note,
+    ///     "+--------------+\n| Database     |\n| migration    |\n| required     |\n| Done.        |\n+--------------+"
+    /// );
+    /// ```
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Shows several related messages in the note, one bullet per message.
+    ///
+    /// The messages replace the note's text. Each one starts on its own
+    /// interior line and wraps independently, with continuation lines
+    /// indented under the message. The icon is drawn once, before the first
+    /// message, and every message shares the note's color. When piped with
+    /// `plain_when_piped`, each message prints on its own `KIND: message` line.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - The messages to show, such as a slice of string slices.
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the messages set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Note;
+    ///
+    /// let note = Note::new("")
+    ///     .lines(&["Config file not found", "Using default settings for now"])
+    ///     .icon("!")
+    ///     .style("+")
+    ///     .width(28)
+    ///     .render();
+    /// assert_eq!(
+    ///     note,
+    ///     "+--------------------------+\n\
+    ///      | ! • Config file not      |\n\
+    ///      |     found                |\n\
+    ///      |   • Using default        |\n\
+    ///      |     settings for now     |\n\
+    ///      +--------------------------+"
+    /// );
+    /// ```
+    pub fn lines(mut self, messages: impl IntoIterator<Item: AsRef<str>>) -> Self {
+        self.messages = messages
+            .into_iter()
+            .map(|m| m.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Adds a hint line at the bottom of the note, such as `Run --help for more`.
+    ///
+    /// The footer is drawn dimmed inside the box, below the body and a
+    /// separator rule. It is kept to one line: a footer wider than the note
+    /// is truncated with `…`. When piped with `plain_when_piped`, it prints
+    /// as the last line.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `footer` - The text of the hint line.
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the footer set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Note;
+    ///
+    /// let note = Note::new("Unknown flag --frobnicate")
+    ///     .footer("Run --help for the list of flags")
+    ///     .style("+")
+    ///     .width(30)
+    ///     .render();
+    /// assert_eq!(
+    ///     note,
+    ///     "+----------------------------+\n\
+    ///      | Unknown flag --frobnicate  |\n\
+    ///      +----------------------------+\n\
+    ///      | Run --help for the list o… |\n\
+    ///      +----------------------------+"
+    /// );
+    /// ```
+    pub fn footer(mut self, footer: impl Into<String>) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
 
-        // Combine icon and text for content
-        let content = match &self.icon {
-            Some(icon) => format!("{} {}", icon, self.text),
-            None => self.text.clone(),
+    /// Sets the alignment of the footer within the note.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining. The default is `Align::Left`.
+    ///
+    /// # Arguments
+    ///
+    /// * `align` - The `Align` to place the footer with.
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the updated footer alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::{Align, Note};
+    ///
+    /// let note = Note::new("Saved")
+    ///     .footer("2 files")
+    ///     .footer_align(Align::Right)
+    ///     .style("+")
+    ///     .width(15)
+    ///     .render();
+    /// assert_eq!(note, "+-------------+\n| Saved       |\n+-------------+\n|     2 files |\n+-------------+");
+    /// ```
+    pub fn footer_align(mut self, align: Align) -> Self {
+        self.footer_align = align;
+        self
+    }
+
+    /// Sets the number of blank lines inside the note above and below the text.
+    ///
+    /// The blank lines are bordered and filled with the background color,
+    /// so the sides stay aligned. The default is no padding.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `top` - The number of blank lines above the text.
+    /// * `bottom` - The number of blank lines below the text.
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the updated padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Note;
+    ///
+    /// let note = Note::new("Hello").style("+").width(11).padding(1, 1).render();
+    /// assert_eq!(
+    ///     note,
+    ///     "+---------+\n|         |\n| Hello   |\n|         |\n+---------+"
+    /// );
+    /// ```
+    pub fn padding(mut self, top: usize, bottom: usize) -> Self {
+        self.padding = (top, bottom);
+        self
+    }
+
+    /// Sets whether the note casts a drop shadow.
+    ///
+    /// The shadow is drawn one column to the right of the note and one row
+    /// below it, so the rendered note is one column wider than its `width`
+    /// and one line taller. It is a dim `▒`, or a gray background when
+    /// Unicode is disabled. Without color output it is drawn as plain `▒`
+    /// characters or spaces, so the size stays the same.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `shadow` - A boolean indicating whether to draw the shadow (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the updated shadow setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Note;
+    ///
+    /// let note = Note::new("Hello").style("+").width(9).shadow(true).render();
+    /// assert_eq!(note, "+-------+ \n| Hello |▒\n+-------+▒\n ▒▒▒▒▒▒▒▒▒");
+    /// ```
+    pub fn shadow(mut self, shadow: bool) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
+    /// Sets whether text too long for the note is truncated.
+    ///
+    /// By default, content wider than the note wraps onto as many interior
+    /// lines as it needs. When enabled, it is cut to fit and marked with `…`
+    /// instead, keeping the note to a single content line.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `truncate` - A boolean indicating whether to truncate long text (`true`) or wrap it onto further lines (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the updated truncation setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Note;
+    ///
+    /// let note = Note::new("Deployment finished").style("+").width(12).truncate(true).render();
+    /// assert_eq!(note, "+----------+\n| Deploym… |\n+----------+");
+    /// ```
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Sets whether the note collapses to a single plain line when piped.
+    ///
+    /// When enabled and standard output is not a terminal, the note prints
+    /// as `KIND: text` (e.g. `WARNING: Disk almost full`) instead of a box,
+    /// which keeps logs and piped output grep-friendly. Notes without a kind
+    /// use `NOTE` as the prefix. A note with a title prints `KIND: title`
+    /// followed by the text on the next line.
+    ///
+    /// This method consumes `self` and returns a new `Note` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `plain` - A boolean indicating whether to print plain lines when piped (`true`) or always draw the box (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Note` instance with the updated setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Note;
+    ///
+    /// // Prints "WARNING: Disk almost full" when redirected to a file
+    /// Note::new("Disk almost full")
+    ///     .kind("warning")
+    ///     .plain_when_piped(true)
+    ///     .print();
+    /// ```
+    pub fn plain_when_piped(mut self, plain: bool) -> Self {
+        self.plain_when_piped = plain;
+        self
+    }
+
+    /// Renders the formatted note into a `String`.
+    ///
+    /// The output is identical to what `print` writes, with lines separated
+    /// by newlines and no trailing newline. Unlike `print`, rendering ignores
+    /// the threshold set by `set_min_note_level`.
+    ///
+    /// Text too long for the note wraps onto further lines, breaking words
+    /// that don't fit on a line of their own. The icon is drawn on the first
+    /// line only, and continuation lines are indented to line up with the
+    /// text after it.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered note.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Note;
+    ///
+    /// let note = Note::new("Saved").style("square").width(12).render();
+    /// assert_eq!(note, "┌──────────┐\n│ Saved    │\n└──────────┘");
+    ///
+    /// let note = Note::new("Run cargo update").icon(">").style("+").width(14).render();
+    /// assert_eq!(
+    ///     note,
+    ///     "+------------+\n| > Run      |\n|   cargo    |\n|   update   |\n+------------+"
+    /// );
+    /// ```
+    pub fn render(&self) -> String {
+        if self.plain_when_piped && !stdout_is_terminal() {
+            let kind = self.kind.as_deref().unwrap_or("note").to_uppercase();
+            let body = match (&self.title, self.messages.is_empty()) {
+                (Some(title), true) => format!("{}: {}\n{}", kind, title, self.text),
+                (Some(title), false) => {
+                    format!("{}: {}\n{}", kind, title, self.messages.join("\n"))
+                }
+                (None, true) => format!("{}: {}", kind, self.text),
+                (None, false) => self
+                    .messages
+                    .iter()
+                    .map(|message| format!("{}: {}", kind, message))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            };
+            return match self.footer {
+                Some(ref footer) => format!("{}\n{}", body, footer),
+                None => body,
+            };
+        }
+
+        let (tl, tr, bl, br, h, v) = get_border(&self.style);
+
+        // Swap preset emoji for ASCII markers when Unicode is disabled
+        let icon = match (&self.icon, &self.kind) {
+            (Some(icon), Some(kind)) if !unicode_enabled() => {
+                match (kind_preset(kind), ascii_icon(kind)) {
+                    (Some((preset, ..)), Some(marker)) if preset == icon => Some(marker),
+                    _ => Some(icon.as_str()),
+                }
+            }
+            (icon, _) => icon.as_deref(),
+        };
+
+        // Combine icon and text for the first line, or icon and title when there is one
+        let lead = self.title.as_ref().unwrap_or(&self.text);
+        let content = match icon {
+            Some(icon) => format!("{} {}", icon, lead),
+            None => lead.clone(),
         };
+        // Wrapped text continues under the text after the icon, not under the icon
+        let indent = icon.map_or(0, |icon| visible_width(icon) + 1);
 
         // Build ANSI style for text and icon
         let mut style = Style::new();
-        if let Some(ref color_name) = self.color {
-            // Normalize the provided color name so matching is case-insensitive
-            let color = color_name.trim().to_lowercase();
-            style = match color.as_str() {
-                "red" => style.fg(Colour::Red),
-                "green" => style.fg(Colour::Green),
-                "yellow" => style.fg(Colour::Yellow),
-                "blue" => style.fg(Colour::Blue),
-                "purple" | "magenta" => style.fg(Colour::Purple),
-                "cyan" => style.fg(Colour::Cyan),
-                "white" => style.fg(Colour::White),
-                _ => style, // Fallback for unsupported colors
-            };
+        let mut fill = Style::new();
+        if let Some(colour) = self.color.as_deref().and_then(parse_colour) {
+            style = style.fg(colour);
+        }
+        if let Some(colour) = self.bg.as_deref().and_then(parse_colour) {
+            style = style.on(colour);
+            fill = fill.on(colour);
         }
         if self.bold {
             style = style.bold();
         }
+        if self.italic {
+            style = style.italic();
+        }
+        if self.underline {
+            style = style.underline();
+        }
+        if self.dim {
+            style = style.dimmed();
+        }
 
         // Apply padding and style to the content
         // Subtract 2 for the vertical borders and two spaces for padding inside
         let content_width = self.width.saturating_sub(4);
-        let padded_content = pad(&content, content_width);
-        let styled_content = style.paint(padded_content);
-
-        // Print the note box
-        println!(
-            "{}{:─<width$}{}",
-            tl,
-            "",
-            tr,
-            width = self.width.saturating_sub(2)
-        ); // Top border
-        println!("{} {} {}", v, styled_content, v); // Content line
-        println!(
-            "{}{:─<width$}{}",
-            bl,
-            "",
-            br,
-            width = self.width.saturating_sub(2)
-        ); // Bottom border
+        // Paint the padding with only the background so it spans the whole
+        // interior without underlining or bolding the blank space
+        let interior_line = |text: &str, style: &Style| {
+            let padding = content_width.saturating_sub(visible_width(text));
+            format!(
+                "{}{}{}{}{}",
+                v,
+                paint(&fill, " "),
+                paint(style, text),
+                paint(&fill, &" ".repeat(padding + 1)),
+                v
+            )
+        };
+
+        let horizontal = h.repeat(self.width.saturating_sub(2));
+        let interior = self.width.saturating_sub(2);
+        let blank = format!("{}{}{}", v, paint(&fill, &" ".repeat(interior)), v);
+
+        // Puts the icon before the first wrapped line and indents the rest under the text
+        let prefix = icon.map(|icon| format!("{} ", icon)).unwrap_or_default();
+        let continuation = " ".repeat(indent);
+        let with_icon = |wrapped: Vec<String>| -> Vec<String> {
+            if wrapped.is_empty() {
+                return vec![content.clone()];
+            }
+            wrapped
+                .iter()
+                .enumerate()
+                .map(|(i, line)| {
+                    let lead = if i == 0 { &prefix } else { &continuation };
+                    format!("{}{}", lead, line)
+                })
+                .collect()
+        };
+
+        let mut lines = vec![format!("{}{}{}", tl, horizontal, tr)]; // Top border
+        lines.extend(std::iter::repeat_n(blank.clone(), self.padding.0));
+        if let Some(ref title) = self.title {
+            let title_lines = if self.truncate {
+                vec![truncate(&content, content_width)]
+            } else {
+                with_icon(hard_wrap(title, content_width.saturating_sub(indent)))
+            };
+            for line in title_lines {
+                lines.push(interior_line(&line, &style.bold())); // Title lines
+            }
+            for line in self.body_lines(content_width) {
+                lines.push(interior_line(&line, &style)); // Body lines
+            }
+        } else if self.truncate && self.messages.is_empty() {
+            lines.push(interior_line(&truncate(&content, content_width), &style)); // Content line
+        } else {
+            // Content lines, with the icon on the first one only
+            for line in with_icon(self.body_lines(content_width.saturating_sub(indent))) {
+                lines.push(interior_line(&line, &style));
+            }
+        }
+        lines.extend(std::iter::repeat_n(blank, self.padding.1));
+        if let Some(ref footer) = self.footer {
+            let (_, _, join_left, join_right, _) = get_junctions(&self.style);
+            lines.push(format!("{}{}{}", join_left, horizontal, join_right));
+            let footer = pad_align(
+                &truncate(footer, content_width),
+                content_width,
+                self.footer_align,
+            );
+            lines.push(interior_line(&footer, &fill.dimmed())); // Footer line
+        }
+        lines.push(format!("{}{}{}", bl, horizontal, br)); // Bottom border
+        if self.shadow {
+            lines = add_shadow(lines, self.width);
+        }
+        lines.join("\n")
+    }
+
+    /// Lays out the text, or the bulleted messages, to fit `width` columns.
+    fn body_lines(&self, width: usize) -> Vec<String> {
+        if self.messages.is_empty() {
+            return hard_wrap(&self.text, width);
+        }
+        let bullet = if unicode_enabled() { "• " } else { "- " };
+        let room = width.saturating_sub(2);
+        let mut lines = Vec::new();
+        for message in &self.messages {
+            let wrapped = if self.truncate {
+                vec![truncate(message, room.max(1))]
+            } else {
+                hard_wrap(message, room)
+            };
+            lines.push(format!("{}{}", bullet, wrapped.first().map_or("", |l| l)));
+            for line in wrapped.iter().skip(1) {
+                lines.push(format!("  {}", line));
+            }
+        }
+        lines
+    }
+
+    /// Prints the formatted note to the console.
+    ///
+    /// This method constructs the note with its borders, icon, styled text,
+    /// and padding, then prints it to standard output. Nothing is printed if
+    /// the note's level is below the threshold set by `set_min_note_level`.
+    pub fn print(&self) {
+        if self.level < min_note_level() {
+            return;
+        }
+        println!("{}", self.render());
+    }
+}
+
+impl Render for Note {
+    fn render(&self) -> String {
+        Note::render(self)
+    }
+
+    fn print(&self) {
+        Note::print(self)
+    }
+}
+
+/// Internal helper function to look up the preset for a note kind.
+///
+/// Built-in kinds are checked first, taking their colors from the active `Theme`,
+/// then kinds added with `register_note_kind`.
+///
+/// # Arguments
+///
+/// * `kind` - A string slice naming the kind ("debug", "info", "tip", "warning", "error",
+///   or a registered name).
+///
+/// # Returns
+///
+/// A tuple of (icon, color, bold, level) if the kind is recognized, otherwise `None`.
+pub(crate) fn kind_preset(kind: &str) -> Option<(&'static str, String, bool, NoteLevel)> {
+    let theme = theme();
+    match kind {
+        "debug" => Some(("🔍", theme.note_debug_color, false, NoteLevel::Debug)),
+        "info" => Some(("ℹ️", theme.note_info_color, false, NoteLevel::Info)),
+        "tip" => Some(("💡", theme.note_tip_color, false, NoteLevel::Tip)),
+        "warning" => Some(("⚠️", theme.note_warning_color, true, NoteLevel::Warning)),
+        "error" => Some(("❌", theme.note_error_color, true, NoteLevel::Error)),
+        _ => {
+            let kinds = NOTE_KINDS.lock().ok()?;
+            let preset = kinds.get(kind)?;
+            Some((
+                preset.icon,
+                preset.color.to_string(),
+                preset.bold,
+                NoteLevel::Info,
+            ))
+        }
+    }
+}
+
+/// Internal helper function to look up the ASCII marker that replaces a kind's
+/// icon when Unicode output is disabled.
+///
+/// # Arguments
+///
+/// * `kind` - A string slice naming the kind ("debug", "info", "tip", "warning", "error").
+///
+/// # Returns
+///
+/// The ASCII marker if the kind is recognized, otherwise `None`.
+pub(crate) fn ascii_icon(kind: &str) -> Option<&'static str> {
+    match kind {
+        "debug" => Some("[?]"),
+        "info" => Some("[i]"),
+        "tip" => Some("[*]"),
+        "warning" => Some("[!]"),
+        "error" => Some("[x]"),
+        _ => None,
     }
 }
 
+/// Adds a drop shadow to the lines of a box `width` columns wide.
+///
+/// The shadow is offset by one row and one column, so the first line gets a
+/// blank column and one shadow row is added below the box.
+pub(crate) fn add_shadow(lines: Vec<String>, width: usize) -> Vec<String> {
+    let shade = if unicode_enabled() {
+        paint(&Style::new().dimmed(), "▒")
+    } else {
+        paint(&Style::new().on(Colour::Fixed(240)), " ")
+    };
+    let mut shadowed: Vec<String> = lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| format!("{}{}", line, if i == 0 { " " } else { &shade }))
+        .collect();
+    shadowed.push(format!(" {}", shade.repeat(width)));
+    shadowed
+}
+
 /// Internal helper function to get border characters based on the specified style.
 ///
 /// # Arguments
@@ -275,8 +1057,10 @@ impl Note {
 /// # Returns
 ///
 /// A tuple of string slices representing (top-left, top-right, bottom-left, bottom-right, horizontal, vertical)
-/// border characters. Defaults to "square" style if an unknown style is provided.
+/// border characters. Defaults to "square" style if an unknown style is provided,
+/// and to "+" for every style when Unicode output is disabled.
 pub(crate) fn get_border(style: &str) -> (&str, &str, &str, &str, &str, &str) {
+    let style = if unicode_enabled() { style } else { "+" };
     match style {
         "rounded" => ("╭", "╮", "╰", "╯", "─", "│"),
         "square" => ("┌", "┐", "└", "┘", "─", "│"),
@@ -284,3 +1068,22 @@ pub(crate) fn get_border(style: &str) -> (&str, &str, &str, &str, &str, &str) {
         _ => ("┌", "┐", "└", "┘", "─", "│"), // Default to square if style is unrecognized
     }
 }
+
+/// Internal helper function to get the junction characters for grids drawn in the specified style.
+///
+/// # Arguments
+///
+/// * `style` - A string slice indicating the desired border style ("rounded", "square", "+").
+///
+/// # Returns
+///
+/// A tuple of string slices representing (top tee, bottom tee, left tee, right tee, cross)
+/// junction characters. Defaults to "square" style if an unknown style is provided,
+/// and to "+" for every style when Unicode output is disabled.
+pub(crate) fn get_junctions(style: &str) -> (&str, &str, &str, &str, &str) {
+    let style = if unicode_enabled() { style } else { "+" };
+    match style {
+        "+" => ("+", "+", "+", "+", "+"),
+        _ => ("┬", "┴", "├", "┤", "┼"), // Rounded and square share the same junctions
+    }
+}
@@ -0,0 +1,174 @@
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::style::{paint, unicode_enabled};
+use crate::theme::theme;
+use ansi_term::Style;
+use std::io::{self, Write};
+
+/// A determinate progress bar that redraws itself in place.
+///
+/// The `ProgressBar` struct shows how far a task with a known amount of work
+/// has come, as a bar followed by a percentage, e.g. `[█████░░░░░]  50%`.
+/// Each call to `set` or `inc` rewrites the current line using a carriage
+/// return, and `finish` moves to the next line. When Unicode is disabled
+/// with `set_unicode(false)`, the bar is drawn with `#` and `-` instead.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::ProgressBar;
+///
+/// let files = ["a.txt", "b.txt", "c.txt"];
+/// let mut bar = ProgressBar::new(files.len() as u64).width(20).color("green");
+/// for _file in files {
+///     // ... process the file ...
+///     bar.inc(1);
+/// }
+/// bar.finish();
+/// ```
+pub struct ProgressBar {
+    total: u64,
+    current: u64,
+    width: usize,
+    color: Option<String>,
+}
+
+impl ProgressBar {
+    /// Creates a new `ProgressBar` for a task with `total` units of work.
+    ///
+    /// The bar starts at zero and is 30 columns wide, not counting the
+    /// brackets and percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `total` - The number of units that make up the whole task.
+    ///
+    /// # Returns
+    ///
+    /// A new `ProgressBar` instance.
+    pub fn new(total: u64) -> Self {
+        Self {
+            total,
+            current: 0,
+            width: 30,
+            color: theme().accent_color,
+        }
+    }
+
+    /// Sets the width of the bar itself.
+    ///
+    /// This method consumes `self` and returns a new `ProgressBar` instance,
+    /// allowing for method chaining. The brackets and percentage are drawn
+    /// outside this width.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The number of columns the bar fills when complete.
+    ///
+    /// # Returns
+    ///
+    /// The `ProgressBar` instance with the updated width.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the color of the filled portion of the bar.
+    ///
+    /// This method consumes `self` and returns a new `ProgressBar` instance,
+    /// allowing for method chaining. Accepts the same colors as `Label::color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `ProgressBar` instance with the updated color.
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Sets the current progress and redraws the bar.
+    ///
+    /// Values larger than the total are clamped to the total.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - The number of units completed so far.
+    pub fn set(&mut self, current: u64) {
+        self.current = current.min(self.total);
+        print!("\r{}", self.render());
+        let _ = io::stdout().flush();
+    }
+
+    /// Advances the progress by `delta` units and redraws the bar.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta` - The number of units completed since the last update.
+    pub fn inc(&mut self, delta: u64) {
+        self.set(self.current.saturating_add(delta));
+    }
+
+    /// Ends the bar, moving the cursor to the next line.
+    ///
+    /// The bar is left on screen as it was last drawn.
+    pub fn finish(&self) {
+        println!();
+    }
+
+    /// Renders the bar at its current progress into a `String`.
+    ///
+    /// The result has no carriage return or newline. A task with a total of
+    /// zero renders as an empty bar at 0%.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the bar and percentage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::ProgressBar;
+    ///
+    /// let mut bar = ProgressBar::new(4).width(8);
+    /// bar.set(2);
+    /// assert_eq!(bar.render(), "[████░░░░]  50%");
+    /// ```
+    pub fn render(&self) -> String {
+        let (fill, empty) = if unicode_enabled() {
+            ("█", "░")
+        } else {
+            ("#", "-")
+        };
+
+        // Guard against dividing by zero for tasks with no work
+        let (filled, percent) = match self.total {
+            0 => (0, 0),
+            total => (
+                (self.current as u128 * self.width as u128 / total as u128) as usize,
+                (self.current as u128 * 100 / total as u128) as u64,
+            ),
+        };
+
+        let mut style = Style::new();
+        if let Some(colour) = self.color.as_deref().and_then(parse_colour) {
+            style = style.fg(colour);
+        }
+
+        format!(
+            "[{}{}] {:>3}%",
+            paint(&style, &fill.repeat(filled)),
+            empty.repeat(self.width - filled),
+            percent
+        )
+    }
+}
+
+impl Render for ProgressBar {
+    fn render(&self) -> String {
+        ProgressBar::render(self)
+    }
+}
@@ -1,4 +1,52 @@
-use crate::layout::{pad, wrap_text};
+use crate::components::Render;
+use crate::components::note::get_border;
+use crate::components::{Label, Tag};
+use crate::layout::{
+    Align, center, default_width, indent_lines, pad_align, terminal_width, truncate, visible_width,
+};
+use crate::style::unicode_enabled;
+use crate::theme::theme;
+
+/// A piece of rich content for `Section::content_rich`.
+///
+/// A span is either plain text, which may wrap at its whitespace, or a
+/// pre-styled inline component such as a `Tag` or `Label`, which is kept
+/// whole when wrapping.
+pub enum Span {
+    /// Plain text.
+    Text(String),
+    /// Pre-rendered text that may contain ANSI escape codes.
+    Styled(String),
+}
+
+impl Span {
+    /// Creates a plain text span.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The string slice to display.
+    pub fn text(text: impl Into<String>) -> Self {
+        Span::Text(text.into())
+    }
+
+    /// Creates a span from a styled `Tag`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The `Tag` to render inline.
+    pub fn tag(tag: Tag) -> Self {
+        Span::Styled(tag.inline())
+    }
+
+    /// Creates a span from a styled `Label`.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The `Label` to render inline.
+    pub fn label(label: Label) -> Self {
+        Span::Styled(label.inline())
+    }
+}
 
 /// A titled block of content, often used for organizing information
 /// with a preceding title and a divider.
@@ -30,13 +78,21 @@ pub struct Section {
     width: usize,
     style: char,
     wrap: bool,
+    indent: usize,
+    hanging_indent: usize,
+    rich: Option<Vec<Span>>,
+    align: Align,
+    title_align: Align,
+    bordered: bool,
+    border: String,
 }
 
 impl Section {
     /// Creates a new `Section` instance with the given title.
     ///
-    /// By default, the section will have no content, a width of 50 characters,
-    /// a default divider style of `'─'`, and text wrapping disabled.
+    /// By default, the section will have no content, a width of 50 characters
+    /// (see `set_default_width`), a default divider style of `'─'`, and text
+    /// wrapping disabled.
     ///
     /// # Arguments
     ///
@@ -45,13 +101,21 @@ impl Section {
     /// # Returns
     ///
     /// A new `Section` instance.
-    pub fn new(title: &str) -> Self {
+    pub fn new(title: impl Into<String>) -> Self {
+        let theme = theme();
         Self {
-            title: title.to_string(),
+            title: title.into(),
             content: String::new(),
-            width: 50,
-            style: '─',
+            width: default_width().unwrap_or(50),
+            style: theme.divider,
             wrap: false,
+            indent: 0,
+            hanging_indent: 0,
+            rich: None,
+            align: Align::Left,
+            title_align: Align::Left,
+            bordered: false,
+            border: theme.border_style.unwrap_or_else(|| "square".to_string()),
         }
     }
 
@@ -68,8 +132,44 @@ impl Section {
     /// # Returns
     ///
     /// The `Section` instance with the updated content.
-    pub fn content(mut self, text: &str) -> Self {
-        self.content = text.to_string();
+    pub fn content(mut self, text: impl Into<String>) -> Self {
+        self.content = text.into();
+        self
+    }
+
+    /// Sets the content of the section from a sequence of rich spans.
+    ///
+    /// This method consumes `self` and returns a new `Section` instance,
+    /// allowing for method chaining. The spans are concatenated in order and
+    /// replace any content set with `content()`. When wrapping is enabled,
+    /// widths are measured on the visible text, so styled spans don't throw
+    /// off line lengths.
+    ///
+    /// # Arguments
+    ///
+    /// * `spans` - A `Vec` of `Span`s making up the content.
+    ///
+    /// # Returns
+    ///
+    /// The `Section` instance with the updated content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::{Section, Span, Tag};
+    ///
+    /// Section::new("Build")
+    ///     .content_rich(vec![
+    ///         Span::text("Status: "),
+    ///         Span::tag(Tag::new("PASSED").color("green")),
+    ///         Span::text(" after 42 tests."),
+    ///     ])
+    ///     .wrap(true)
+    ///     .width(30)
+    ///     .print();
+    /// ```
+    pub fn content_rich(mut self, spans: Vec<Span>) -> Self {
+        self.rich = Some(spans);
         self
     }
 
@@ -91,6 +191,22 @@ impl Section {
         self
     }
 
+    /// Sizes the section to span the full width of the terminal.
+    ///
+    /// This method consumes `self` and returns a new `Section` instance,
+    /// allowing for method chaining. The width is read from the terminal
+    /// when this method is called. When standard output isn't a
+    /// terminal, the default width is used instead: 50, or the width set
+    /// with `set_default_width`.
+    ///
+    /// # Returns
+    ///
+    /// The `Section` instance with its width set to the terminal width.
+    pub fn full_width(mut self) -> Self {
+        self.width = terminal_width().or_else(default_width).unwrap_or(50);
+        self
+    }
+
     /// Sets the character used for the horizontal divider within the section.
     ///
     /// This method consumes `self` and returns a new `Section` instance,
@@ -129,22 +245,343 @@ impl Section {
         self
     }
 
+    /// Sets how many spaces every content line is indented by.
+    ///
+    /// This method consumes `self` and returns a new `Section` instance,
+    /// allowing for method chaining. When wrapping is enabled, lines wrap
+    /// early enough to fit after the indent. The indent is clamped to the
+    /// content width.
+    ///
+    /// # Arguments
+    ///
+    /// * `spaces` - The number of spaces to put before each line.
+    ///
+    /// # Returns
+    ///
+    /// The `Section` instance with the updated indent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Section;
+    ///
+    /// let quote = Section::new("Quote")
+    ///     .content("Simple is better than complex.")
+    ///     .width(20)
+    ///     .wrap(true)
+    ///     .indent(4)
+    ///     .style('-')
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     quote,
+    ///     "Quote:\n--------------------\n    Simple is better\n    than complex.   "
+    /// );
+    /// ```
+    pub fn indent(mut self, spaces: usize) -> Self {
+        self.indent = spaces;
+        self
+    }
+
+    /// Sets how many extra spaces the continuation lines of wrapped paragraphs are indented by.
+    ///
+    /// This method consumes `self` and returns a new `Section` instance,
+    /// allowing for method chaining. The first line of each paragraph stays
+    /// flush, which suits definition-style text. It only has an effect when
+    /// wrapping is enabled, and adds to any `indent`.
+    ///
+    /// # Arguments
+    ///
+    /// * `spaces` - The number of extra spaces before each continuation line.
+    ///
+    /// # Returns
+    ///
+    /// The `Section` instance with the updated hanging indent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Section;
+    ///
+    /// let terms = Section::new("Glossary")
+    ///     .content("Crate: a compilation unit in Rust")
+    ///     .width(20)
+    ///     .wrap(true)
+    ///     .hanging_indent(2)
+    ///     .style('-')
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     terms,
+    ///     "Glossary:\n--------------------\nCrate: a            \n  compilation unit  \n  in Rust           "
+    /// );
+    /// ```
+    pub fn hanging_indent(mut self, spaces: usize) -> Self {
+        self.hanging_indent = spaces;
+        self
+    }
+
+    /// Sets the horizontal alignment of each content line.
+    ///
+    /// This method consumes `self` and returns a new `Section` instance,
+    /// allowing for method chaining. Lines are aligned within the section
+    /// width after wrapping, measured by display width so emoji and wide
+    /// characters stay visually centered. The default is `Align::Left`.
+    ///
+    /// # Arguments
+    ///
+    /// * `align` - The `Align` to apply to the content.
+    ///
+    /// # Returns
+    ///
+    /// The `Section` instance with the updated content alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::{Align, Section};
+    ///
+    /// let section = Section::new("Info")
+    ///     .content("Hi")
+    ///     .width(8)
+    ///     .align(Align::Center)
+    ///     .render();
+    /// assert_eq!(section, "Info:\n────────\n   Hi   ");
+    /// ```
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets the horizontal alignment of the title line.
+    ///
+    /// This method consumes `self` and returns a new `Section` instance,
+    /// allowing for method chaining. The default, `Align::Left`, prints the
+    /// title with no padding. Other alignments pad the title to the
+    /// section width.
+    ///
+    /// # Arguments
+    ///
+    /// * `align` - The `Align` to apply to the title.
+    ///
+    /// # Returns
+    ///
+    /// The `Section` instance with the updated title alignment.
+    pub fn title_align(mut self, align: Align) -> Self {
+        self.title_align = align;
+        self
+    }
+
+    /// Sets whether the section is drawn inside a frame.
+    ///
+    /// This method consumes `self` and returns a new `Section` instance,
+    /// allowing for method chaining. A bordered section embeds its title in
+    /// the top border and draws vertical borders beside the content, keeping
+    /// the total width set with `width()`. A title too wide for the border
+    /// is truncated with `…`.
+    ///
+    /// The frame uses square corners, or the theme's border style if one is
+    /// set. When the divider `style()` is an ASCII character such as `'-'`
+    /// or `'='`, the frame is drawn in ASCII instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `bordered` - A boolean indicating whether to draw the frame (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Section` instance with the updated border setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Section;
+    ///
+    /// let section = Section::new("Info")
+    ///     .content("Hello")
+    ///     .width(14)
+    ///     .bordered(true)
+    ///     .render();
+    /// assert_eq!(
+    ///     section,
+    ///     "┌─ Info ─────┐\n\
+    ///      │ Hello      │\n\
+    ///      └────────────┘"
+    /// );
+    /// ```
+    pub fn bordered(mut self, bordered: bool) -> Self {
+        self.bordered = bordered;
+        self
+    }
+
+    /// Renders the `Section` into a `String`.
+    ///
+    /// The output is identical to what `print` writes, with lines separated
+    /// by newlines and no trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Section;
+    ///
+    /// let section = Section::new("Info").content("Hello").width(8).render();
+    /// assert_eq!(section, "Info:\n────────\nHello   ");
+    /// ```
+    pub fn render(&self) -> String {
+        if self.bordered {
+            return self.render_bordered();
+        }
+
+        let rule = match self.style {
+            '─' if !unicode_enabled() => '-',
+            style => style,
+        };
+        let title = format!("{}:", self.title);
+        let title = match self.title_align {
+            Align::Left => title,
+            align => pad_align(&title, self.width, align),
+        };
+        let mut out = vec![title, rule.to_string().repeat(self.width)];
+        out.extend(self.content_lines(self.width));
+        out.join("\n")
+    }
+
+    /// Renders the section inside a frame with the title in the top border.
+    fn render_bordered(&self) -> String {
+        // Plain ASCII rule characters select the ASCII frame
+        let charset = if self.style.is_ascii() {
+            "+"
+        } else {
+            self.border.as_str()
+        };
+        let (tl, tr, bl, br, h, v) = get_border(charset);
+        let inner = self.width.saturating_sub(2);
+
+        // The title sits between "─ " and " ─", leaving at least one rule character after it
+        let top = if self.title.is_empty() || inner < 5 {
+            h.repeat(inner)
+        } else {
+            let title = truncate(&self.title, inner - 4);
+            let rest = inner - 3 - visible_width(&title);
+            format!("{} {} {}", h, title, h.repeat(rest))
+        };
+
+        let mut out = vec![format!("{}{}{}", tl, top, tr)];
+        for line in self.content_lines(inner.saturating_sub(2)) {
+            out.push(format!("{} {} {}", v, line, v));
+        }
+        out.push(format!("{}{}{}", bl, h.repeat(inner), br));
+        out.join("\n")
+    }
+
+    /// Lays out and aligns the content lines to the given width.
+    fn content_lines(&self, width: usize) -> Vec<String> {
+        if let Some(ref spans) = self.rich {
+            let indent = self.indent.min(width);
+            let hanging = self.hanging_indent.min(width - indent);
+            return self
+                .rich_lines(spans, width - indent - hanging)
+                .iter()
+                .enumerate()
+                .map(|(i, line)| {
+                    let lead = if i == 0 { indent } else { indent + hanging };
+                    self.align_styled(&format!("{}{}", " ".repeat(lead), line), width)
+                })
+                .collect();
+        }
+
+        let lines = indent_lines(
+            &self.content,
+            width,
+            self.wrap,
+            self.indent,
+            self.hanging_indent,
+        );
+        lines
+            .iter()
+            .map(|line| pad_align(line, width, self.align))
+            .collect()
+    }
+
     /// Prints the `Section` to the console.
     ///
     /// This method renders the section, including its title, a horizontal
     /// divider, and its content (with optional wrapping and padding),
     /// to standard output.
     pub fn print(&self) {
-        println!("{}:", self.title);
-        println!("{}", self.style.to_string().repeat(self.width));
-        let lines = if self.wrap {
-            wrap_text(&self.content, self.width)
-        } else {
-            self.content.lines().map(|l| l.to_string()).collect()
-        };
+        Render::print(self)
+    }
+
+    /// Aligns a line that may contain escape codes, measuring only its visible text.
+    fn align_styled(&self, line: &str, width: usize) -> String {
+        let padding = width.saturating_sub(visible_width(line));
+        match self.align {
+            Align::Left => format!("{}{}", line, " ".repeat(padding)),
+            Align::Center => center(line, width),
+            Align::Right => format!("{}{}", " ".repeat(padding), line),
+        }
+    }
 
-        for line in lines {
-            println!("{}", pad(&line, self.width));
+    /// Lays out rich spans into lines, wrapping on visible width when enabled.
+    fn rich_lines(&self, spans: &[Span], width: usize) -> Vec<String> {
+        if !self.wrap {
+            let joined: String = spans
+                .iter()
+                .map(|span| match span {
+                    Span::Text(text) | Span::Styled(text) => text.as_str(),
+                })
+                .collect();
+            return joined.lines().map(|l| l.to_string()).collect();
         }
+
+        // Styled spans join the word they touch; whitespace in text separates words
+        let mut words = Vec::new();
+        let mut current = String::new();
+        for span in spans {
+            match span {
+                Span::Text(text) => {
+                    for c in text.chars() {
+                        if c.is_whitespace() {
+                            if !current.is_empty() {
+                                words.push(std::mem::take(&mut current));
+                            }
+                        } else {
+                            current.push(c);
+                        }
+                    }
+                }
+                Span::Styled(text) => current.push_str(text),
+            }
+        }
+        if !current.is_empty() {
+            words.push(current);
+        }
+
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        for word in words {
+            let line_width = visible_width(&line);
+            if line_width > 0 && line_width + 1 + visible_width(&word) > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&word);
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+impl Render for Section {
+    fn render(&self) -> String {
+        Section::render(self)
     }
 }
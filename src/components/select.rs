@@ -0,0 +1,345 @@
+use crate::components::note::get_border;
+use crate::layout::{default_width, pad};
+use crate::style::paint;
+use crate::theme::theme;
+use ansi_term::Style;
+use std::io::{self, Write};
+
+/// An interactive prompt for picking one option from a numbered menu.
+///
+/// `Select` draws a boxed prompt in the same way as `Confirm`, lists the
+/// options numbered from 1, and reads the choice from standard input. The
+/// user may type an option's number or its label. Invalid input prints a
+/// hint and asks again.
+///
+/// # Examples
+///
+/// ```no_run
+/// use cliux::Select;
+///
+/// let colors = ["red", "green", "blue"];
+/// let choice = Select::new("Pick a color")
+///     .options(&colors)
+///     .default(0)
+///     .color("cyan")
+///     .prompt();
+///
+/// println!("You picked {}", colors[choice]);
+/// ```
+pub struct Select {
+    label: String,
+    options: Vec<String>,
+    default: Option<usize>,
+    color: Option<String>,
+    bold: bool,
+    style: Option<String>,
+    width: usize,
+    symbol: String,
+    max_attempts: Option<usize>,
+}
+
+impl Select {
+    /// Creates a new `Select` prompt with the given label and no options.
+    ///
+    /// By default, the prompt has "square" borders, a width of 40
+    /// characters (see `set_default_width`), and no default option.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The question displayed at the top of the box.
+    ///
+    /// # Returns
+    ///
+    /// A new `Select` instance.
+    pub fn new(label: impl Into<String>) -> Self {
+        let theme = theme();
+        Self {
+            label: label.into(),
+            options: Vec::new(),
+            default: None,
+            color: theme.accent_color,
+            bold: false,
+            style: Some(theme.border_style.unwrap_or_else(|| "square".to_string())),
+            // The borders are drawn outside `width`
+            width: default_width().map(|w| w.saturating_sub(2)).unwrap_or(40),
+            symbol: "> ".to_string(),
+            max_attempts: None,
+        }
+    }
+
+    /// Sets the options to choose from, in display order.
+    ///
+    /// This method consumes `self` and returns a new `Select` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The option labels, such as a slice of string slices or a `Vec<String>`.
+    ///
+    /// # Returns
+    ///
+    /// The `Select` instance with the options set.
+    pub fn options(mut self, options: impl IntoIterator<Item: AsRef<str>>) -> Self {
+        self.options = options
+            .into_iter()
+            .map(|o| o.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Sets the option chosen when the user submits an empty line.
+    ///
+    /// This method consumes `self` and returns a new `Select` instance,
+    /// allowing for method chaining. The default option is marked in the
+    /// menu. Without a default, an empty line re-prompts.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based index of the default option.
+    ///
+    /// # Returns
+    ///
+    /// The `Select` instance with the default option set.
+    pub fn default(mut self, index: usize) -> Self {
+        self.default = Some(index);
+        self
+    }
+
+    /// Sets the color of the label and options.
+    ///
+    /// This method consumes `self` and returns a new `Select` instance,
+    /// allowing for method chaining. Accepts the same colors as `Confirm`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `Select` instance with the updated color.
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Sets whether the label and options should be bold.
+    ///
+    /// This method consumes `self` and returns a new `Select` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `bold` - A boolean indicating whether the text should be bold (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Select` instance with the updated bold setting.
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    /// Sets the border style of the prompt box.
+    ///
+    /// This method consumes `self` and returns a new `Select` instance,
+    /// allowing for method chaining. Supports the same styles as `Note`:
+    /// `"rounded"`, `"square"` (default), and `"+"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - A string slice representing the desired border style.
+    ///
+    /// # Returns
+    ///
+    /// The `Select` instance with the updated border style.
+    pub fn style(mut self, style: &str) -> Self {
+        self.style = Some(style.to_string());
+        self
+    }
+
+    /// Sets the interior width of the prompt box.
+    ///
+    /// This method consumes `self` and returns a new `Select` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The desired width between the borders in characters.
+    ///
+    /// # Returns
+    ///
+    /// The `Select` instance with the updated width.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the symbol printed before the cursor on the input line.
+    ///
+    /// The default is `"> "`. The symbol is painted with the same color and
+    /// boldness as the label, and the cursor is left directly after it.
+    ///
+    /// This method consumes `self` and returns a new `Select` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The string slice to print before the cursor (e.g. `"❯ "`, `"$ "`).
+    ///
+    /// # Returns
+    ///
+    /// The `Select` instance with the updated prompt symbol.
+    pub fn prompt_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = symbol.into();
+        self
+    }
+
+    /// Limits how many times an invalid answer is asked again.
+    ///
+    /// By default the prompt keeps asking until it gets a valid answer.
+    ///
+    /// This method consumes `self` and returns a new `Select` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempts` - The maximum number of answers to read, including the first.
+    ///
+    /// # Returns
+    ///
+    /// The `Select` instance with the updated attempt limit.
+    pub fn max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Shows the menu and waits for the user to pick an option.
+    ///
+    /// This is `try_prompt` for callers that can't recover from I/O errors.
+    /// When standard input is closed or every attempt was invalid, the
+    /// default is returned.
+    ///
+    /// # Returns
+    ///
+    /// The zero-based index of the chosen option.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no options were set, since no answer could be valid. Also
+    /// panics if reading from standard input or writing to standard output
+    /// fails, or if no valid answer was given and there is no default.
+    pub fn prompt(&self) -> usize {
+        match self.try_prompt() {
+            Ok(index) => index,
+            Err(e) => match (e.kind(), self.valid_default()) {
+                (io::ErrorKind::UnexpectedEof | io::ErrorKind::InvalidInput, Some(index)) => index,
+                _ => panic!("failed to read input: {}", e),
+            },
+        }
+    }
+
+    /// Shows the menu and waits for the user to pick an option, returning any I/O error.
+    ///
+    /// The user may enter an option's number (starting at 1) or its label,
+    /// ignoring case. An empty line picks the default, if one is set. Any
+    /// other input prints a hint and asks again, up to the limit set by
+    /// `max_attempts`.
+    ///
+    /// # Returns
+    ///
+    /// The zero-based index of the chosen option.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from standard input or flushing standard
+    /// output fails. If standard input is closed (EOF) before an answer is
+    /// given, the default is returned, or an `UnexpectedEof` error when
+    /// there is no default. If every attempt allowed by `max_attempts` is
+    /// invalid, an `InvalidInput` error is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no options were set, since no answer could be valid.
+    pub fn try_prompt(&self) -> io::Result<usize> {
+        assert!(!self.options.is_empty(), "Select needs at least one option");
+
+        let (tl, tr, bl, br, h, v) = get_border(self.style.as_deref().unwrap_or("plain"));
+        let mut style = Style::new();
+
+        if let Some(ref color) = self.color
+            && let Some(colour) = crate::components::label::parse_colour(color)
+        {
+            style = style.fg(colour);
+        }
+        if self.bold {
+            style = style.bold();
+        }
+
+        // Draw box
+        println!("{}{}{}", tl, h.repeat(self.width), tr);
+        println!("{}{}{}", v, paint(&style, &pad(&self.label, self.width)), v);
+        for (i, option) in self.options.iter().enumerate() {
+            let marker = if self.default == Some(i) {
+                " (default)"
+            } else {
+                ""
+            };
+            let line = pad(&format!("  {}. {}{}", i + 1, option, marker), self.width);
+            println!("{}{}{}", v, paint(&style, &line), v);
+        }
+        println!("{}{}{}", bl, h.repeat(self.width), br);
+
+        let mut attempts = 0;
+        loop {
+            // Input line
+            print!("{}", paint(&style, &self.symbol));
+            io::stdout().flush()?;
+
+            // A closed stdin reads zero bytes, unlike an empty line
+            let mut input = String::new();
+            if io::stdin().read_line(&mut input)? == 0 {
+                println!();
+                return self.valid_default().ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "stdin closed before an answer",
+                    )
+                });
+            }
+
+            if let Some(index) = self.parse_choice(input.trim()) {
+                return Ok(index);
+            }
+
+            attempts += 1;
+            if self.max_attempts.is_some_and(|max| attempts >= max) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("no valid answer after {} attempts", attempts),
+                ));
+            }
+            println!(
+                "Invalid input. Please enter a number from 1 to {}.",
+                self.options.len()
+            );
+        }
+    }
+
+    /// Returns the default option, if one is set and within range.
+    fn valid_default(&self) -> Option<usize> {
+        self.default.filter(|&i| i < self.options.len())
+    }
+
+    /// Maps the user's answer to an option index, or `None` if it matches nothing.
+    fn parse_choice(&self, input: &str) -> Option<usize> {
+        if input.is_empty() {
+            return self.valid_default();
+        }
+        if let Ok(n) = input.parse::<usize>() {
+            return (1..=self.options.len()).contains(&n).then(|| n - 1);
+        }
+        self.options
+            .iter()
+            .position(|option| option.eq_ignore_ascii_case(input))
+    }
+}
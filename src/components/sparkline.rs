@@ -0,0 +1,117 @@
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::layout::sparkline;
+use crate::style::paint;
+use ansi_term::Style;
+use std::fmt;
+
+/// A tiny inline bar chart of a series of values.
+///
+/// The `Sparkline` struct draws one block character per value, using the
+/// eight heights `▁▂▃▄▅▆▇█` scaled between the smallest and largest value
+/// in the series. It is a single line as wide as the series, so it fits in
+/// a `Table` cell, a `KeyValue` value, or a line of text. A series where
+/// every value is equal is drawn as a flat mid-level line, and NaN or
+/// infinite values are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::{Sparkline, Table};
+///
+/// let latency = Sparkline::new(&[1.0, 3.0, 2.0, 8.0]).color("green");
+/// assert_eq!(latency.inline(), "▁▃▂█");
+///
+/// let table = Table::new()
+///     .headers(["Service", "Latency"])
+///     .row(["api".to_string(), latency.inline()]);
+/// ```
+pub struct Sparkline {
+    values: Vec<f64>,
+    color: Option<String>,
+}
+
+impl Sparkline {
+    /// Creates a new `Sparkline` from a series of values.
+    ///
+    /// By default, the sparkline has no color.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The series of values to draw, in order.
+    ///
+    /// # Returns
+    ///
+    /// A new `Sparkline` instance.
+    pub fn new(values: &[f64]) -> Self {
+        Self {
+            values: values.to_vec(),
+            color: None,
+        }
+    }
+
+    /// Sets the color of the blocks.
+    ///
+    /// This method consumes `self` and returns a new `Sparkline` instance,
+    /// allowing for method chaining. Accepts the same colors as `Label::color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `Sparkline` instance with the updated color.
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Returns the styled sparkline as a `String`.
+    ///
+    /// An empty series returns an empty string, with no escape codes.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing one block character per finite value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Sparkline;
+    ///
+    /// assert_eq!(Sparkline::new(&[5.0, 5.0, 5.0]).inline(), "▄▄▄");
+    /// assert_eq!(Sparkline::new(&[1.0, f64::NAN, 2.0]).inline(), "▁█");
+    /// assert_eq!(Sparkline::new(&[]).color("red").inline(), "");
+    /// ```
+    pub fn inline(&self) -> String {
+        let blocks = sparkline(&self.values);
+        if blocks.is_empty() {
+            return blocks;
+        }
+
+        let mut style = Style::new();
+        if let Some(colour) = self.color.as_deref().and_then(parse_colour) {
+            style = style.fg(colour);
+        }
+        paint(&style, &blocks)
+    }
+
+    /// Prints the sparkline to the console.
+    pub fn print(&self) {
+        Render::print(self)
+    }
+}
+
+impl Render for Sparkline {
+    fn render(&self) -> String {
+        self.inline()
+    }
+}
+
+impl fmt::Display for Sparkline {
+    /// Writes the same styled text as `inline`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.inline())
+    }
+}
@@ -0,0 +1,220 @@
+use crate::components::label::parse_colour;
+use crate::style::{paint, unicode_enabled};
+use crate::term::clear_line;
+use crate::theme::theme;
+use ansi_term::Style;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// Returns the animation frames, falling back to ASCII when Unicode is disabled.
+pub(crate) fn frames() -> &'static [&'static str] {
+    if unicode_enabled() {
+        &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]
+    } else {
+        &["|", "/", "-", "\\"]
+    }
+}
+
+/// Replaces the current line with `line`, leaving the cursor after it.
+pub(crate) fn draw_line(line: &str) {
+    clear_line();
+    print!("{}", line);
+    let _ = io::stdout().flush();
+}
+
+/// An animated spinner for async programs, driven by a `tokio` task.
+///
+/// The `AsyncSpinner` struct shows that a task of unknown length is still
+/// running, as a spinning frame followed by a message, e.g. `⠹ Fetching`.
+/// Instead of blocking a thread, `start` spawns a task that redraws the
+/// line on a `tokio` interval and returns a `SpinnerGuard`. The line is
+/// cleared when the guard is stopped or dropped, including when the future
+/// holding it is cancelled or unwinds from a panic. When Unicode is
+/// disabled with `set_unicode(false)`, the frames are `|`, `/`, `-`, and `\`.
+///
+/// Only available with the `tokio` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use cliux::AsyncSpinner;
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let spinner = AsyncSpinner::new("Fetching releases").color("cyan").start();
+///     tokio::time::sleep(Duration::from_secs(2)).await;
+///     spinner.stop().await;
+///     println!("Fetched 3 releases");
+/// }
+/// ```
+pub struct AsyncSpinner {
+    message: String,
+    color: Option<String>,
+    interval: Duration,
+}
+
+impl AsyncSpinner {
+    /// Creates a new `AsyncSpinner` with the given message.
+    ///
+    /// By default, the frames advance every 80 milliseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The text shown after the spinning frame.
+    ///
+    /// # Returns
+    ///
+    /// A new `AsyncSpinner` instance.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            color: theme().accent_color,
+            interval: Duration::from_millis(80),
+        }
+    }
+
+    /// Sets the color of the spinning frame.
+    ///
+    /// This method consumes `self` and returns a new `AsyncSpinner` instance,
+    /// allowing for method chaining. Accepts the same colors as `Label::color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `AsyncSpinner` instance with the updated color.
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Sets how long each frame is shown before the next one is drawn.
+    ///
+    /// This method consumes `self` and returns a new `AsyncSpinner` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The time between frames.
+    ///
+    /// # Returns
+    ///
+    /// The `AsyncSpinner` instance with the updated interval.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Renders the frame shown on the given tick, followed by the message.
+    ///
+    /// The frames repeat, so any tick is valid. The result has no carriage
+    /// return or newline.
+    ///
+    /// # Arguments
+    ///
+    /// * `tick` - The number of frames drawn before this one.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the frame and message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::AsyncSpinner;
+    ///
+    /// let spinner = AsyncSpinner::new("Loading");
+    /// assert_eq!(spinner.render_frame(0), "⠋ Loading");
+    /// assert_eq!(spinner.render_frame(11), "⠙ Loading");
+    /// ```
+    pub fn render_frame(&self, tick: usize) -> String {
+        let frames = frames();
+        let mut style = Style::new();
+        if let Some(colour) = self.color.as_deref().and_then(parse_colour) {
+            style = style.fg(colour);
+        }
+        format!(
+            "{} {}",
+            paint(&style, frames[tick % frames.len()]),
+            self.message
+        )
+    }
+
+    /// Starts the animation on a new `tokio` task.
+    ///
+    /// The first frame is drawn straight away. The animation runs until the
+    /// returned guard is stopped or dropped.
+    ///
+    /// # Returns
+    ///
+    /// A `SpinnerGuard` that stops the animation and clears its line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a `tokio` runtime.
+    pub fn start(self) -> SpinnerGuard {
+        let stopped = Arc::new(Mutex::new(false));
+        let state = Arc::clone(&stopped);
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.interval);
+            for tick in 0.. {
+                ticker.tick().await;
+                // Hold the lock while drawing so a frame can't land after the guard clears the line
+                let stopped = state.lock().unwrap_or_else(PoisonError::into_inner);
+                if *stopped {
+                    break;
+                }
+                draw_line(&self.render_frame(tick));
+            }
+        });
+        SpinnerGuard {
+            stopped,
+            task: Some(task),
+        }
+    }
+}
+
+/// Keeps an `AsyncSpinner` animating until it is stopped or dropped.
+///
+/// Dropping the guard stops the animation and clears the line, so a
+/// cancelled or panicking task doesn't leave a stale frame behind. Call
+/// `stop` instead to also wait for the animation task to finish.
+#[must_use = "dropping the guard stops the spinner immediately"]
+pub struct SpinnerGuard {
+    stopped: Arc<Mutex<bool>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl SpinnerGuard {
+    /// Stops the animation, clears the line, and waits for the task to end.
+    pub async fn stop(mut self) {
+        self.halt();
+        if let Some(task) = self.task.take() {
+            // The task was aborted, so its cancellation error is expected
+            let _ = task.await;
+        }
+    }
+
+    /// Marks the spinner as stopped and clears its line, at most once.
+    fn halt(&mut self) {
+        let mut stopped = self.stopped.lock().unwrap_or_else(PoisonError::into_inner);
+        if !*stopped {
+            *stopped = true;
+            clear_line();
+        }
+        if let Some(task) = &self.task {
+            task.abort();
+        }
+    }
+}
+
+impl Drop for SpinnerGuard {
+    fn drop(&mut self) {
+        self.halt();
+    }
+}
@@ -1,4 +1,50 @@
-use crate::layout::pad;
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::components::note::{get_border, get_junctions};
+use crate::layout::{
+    Align, hard_wrap, pad_align, sparkline, terminal_width, truncate, visible_width,
+};
+use crate::style::paint;
+use crate::theme::theme;
+use ansi_term::{Colour, Style};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// The background of striped rows: a dark gray that stays subtle on most themes.
+const STRIPE: Colour = Colour::Fixed(236);
+
+/// A type that can be displayed as one row of a `Table`.
+///
+/// Implement this trait to build a table straight from a slice of your own
+/// structs with `Table::from_rows`.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::TableRow;
+///
+/// struct Crate {
+///     name: &'static str,
+///     downloads: u64,
+/// }
+///
+/// impl TableRow for Crate {
+///     fn headers() -> Vec<String> {
+///         vec!["Name".to_string(), "Downloads".to_string()]
+///     }
+///
+///     fn cells(&self) -> Vec<String> {
+///         vec![self.name.to_string(), self.downloads.to_string()]
+///     }
+/// }
+/// ```
+pub trait TableRow {
+    /// Returns the column headers shared by every row of this type.
+    fn headers() -> Vec<String>;
+
+    /// Returns the cells of this row, one per header.
+    fn cells(&self) -> Vec<String>;
+}
 
 /// A component for displaying tabular data in the terminal.
 ///
@@ -50,18 +96,62 @@ use crate::layout::pad;
 ///     .widths(&[20, 50])
 ///     .print();
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Table {
     headers: Option<Vec<String>>,
     rows: Vec<Vec<String>>,
     bordered: bool,
     widths: Option<Vec<usize>>,
+    no_wrap: Vec<usize>,
+    style: String,
+    sparkline_cols: Vec<usize>,
+    max_width: Option<usize>,
+    sticky: usize,
+    alignments: Vec<Align>,
+    truncate: bool,
+    striped: bool,
+    header_color: Option<String>,
+    header_bold: bool,
+    row_separators: bool,
+    spans: HashMap<usize, Vec<usize>>,
+    footer: Option<Vec<String>>,
+    footer_bold: bool,
+    compact: bool,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Vec<Vec<String>>> for Table {
+    /// Creates a table with the given rows and no headers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let data = vec![
+    ///     vec!["a".to_string(), "1".to_string()],
+    ///     vec!["b".to_string(), "2".to_string()],
+    /// ];
+    /// let table = Table::from(data).render();
+    /// assert_eq!(table, "+---+---+\n| a | 1 |\n| b | 2 |\n+---+---+");
+    /// ```
+    fn from(rows: Vec<Vec<String>>) -> Self {
+        let mut table = Self::new();
+        table.rows = rows;
+        table
+    }
 }
 
 impl Table {
     /// Creates a new, empty `Table` instance.
     ///
     /// By default, the table will have no headers or rows,
-    /// will be bordered, and will auto-calculate column widths
+    /// will be bordered with ASCII `"+"` style borders, and will auto-calculate column widths
     /// based on content if not explicitly set.
     ///
     /// # Returns
@@ -73,24 +163,136 @@ impl Table {
             rows: Vec::new(),
             bordered: true,
             widths: None,
+            no_wrap: Vec::new(),
+            style: theme().border_style.unwrap_or_else(|| "+".to_string()),
+            sparkline_cols: Vec::new(),
+            max_width: None,
+            sticky: 0,
+            alignments: Vec::new(),
+            truncate: false,
+            striped: false,
+            header_color: None,
+            header_bold: false,
+            row_separators: false,
+            spans: HashMap::new(),
+            footer: None,
+            footer_bold: false,
+            compact: false,
         }
     }
 
+    /// Creates a new `Table` from a slice of values implementing `TableRow`.
+    ///
+    /// The headers are taken from `T::headers()` and each value becomes one
+    /// row. Rows with fewer cells than headers are padded with empty cells.
+    /// Rows with more cells than headers widen the table, with blank headers
+    /// over the extra columns, so no data is lost and the grid always lines
+    /// up. The result can be customized further with the usual builder
+    /// methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - A slice of values to display, one per row.
+    ///
+    /// # Returns
+    ///
+    /// A new `Table` instance with headers and rows set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::{Table, TableRow};
+    ///
+    /// struct Task {
+    ///     name: &'static str,
+    ///     done: bool,
+    /// }
+    ///
+    /// impl TableRow for Task {
+    ///     fn headers() -> Vec<String> {
+    ///         vec!["Task".to_string(), "Done".to_string()]
+    ///     }
+    ///
+    ///     fn cells(&self) -> Vec<String> {
+    ///         vec![self.name.to_string(), self.done.to_string()]
+    ///     }
+    /// }
+    ///
+    /// let tasks = [
+    ///     Task { name: "Write docs", done: true },
+    ///     Task { name: "Ship", done: false },
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     Table::from_rows(&tasks).render(),
+    ///     "+------------+-------+\n\
+    ///      | Task       | Done  |\n\
+    ///      +------------+-------+\n\
+    ///      | Write docs | true  |\n\
+    ///      | Ship       | false |\n\
+    ///      +------------+-------+"
+    /// );
+    /// ```
+    ///
+    /// Cells beyond the headers are kept in an extra column:
+    /// ```
+    /// use cliux::{Table, TableRow};
+    ///
+    /// struct Entry(&'static str);
+    ///
+    /// impl TableRow for Entry {
+    ///     fn headers() -> Vec<String> {
+    ///         vec!["Name".to_string()]
+    ///     }
+    ///
+    ///     fn cells(&self) -> Vec<String> {
+    ///         vec![self.0.to_string(), "extra".to_string()]
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Table::from_rows(&[Entry("Ada")]).render(),
+    ///     "+------+-------+\n| Name |       |\n+------+-------+\n| Ada  | extra |\n+------+-------+"
+    /// );
+    /// ```
+    pub fn from_rows<T: TableRow>(rows: &[T]) -> Self {
+        let headers = T::headers();
+        let mut table = Self::new();
+        table.rows = rows
+            .iter()
+            .map(|row| {
+                let mut cells = row.cells();
+                if cells.len() < headers.len() {
+                    cells.resize(headers.len(), String::new());
+                }
+                cells
+            })
+            .collect();
+        table.headers = Some(headers);
+        table
+    }
+
     /// Sets the headers for the table.
     ///
     /// This method consumes `self` and returns a new `Table` instance,
     /// allowing for method chaining. The number of headers defines the
-    /// number of columns in the table.
+    /// number of columns in the table, unless a row has more cells, in which
+    /// case the extra columns get blank headers.
     ///
     /// # Arguments
     ///
-    /// * `headers` - A slice of string slices, where each slice is a column header.
+    /// * `headers` - The column headers, such as a slice of string slices or a `Vec<String>`.
     ///
     /// # Returns
     ///
     /// The `Table` instance with the updated headers.
-    pub fn headers(mut self, headers: &[&str]) -> Self {
-        self.headers = Some(headers.iter().map(|s| s.to_string()).collect());
+    pub fn headers(mut self, headers: impl IntoIterator<Item: AsRef<str>>) -> Self {
+        self.headers = Some(
+            headers
+                .into_iter()
+                .map(|s| s.as_ref().to_string())
+                .collect(),
+        );
         self
     }
 
@@ -103,13 +305,344 @@ impl Table {
     ///
     /// # Arguments
     ///
-    /// * `row` - A slice of string slices, where each slice is a cell in the row.
+    /// * `row` - The cells of the row, such as a slice of string slices or a `Vec<String>`.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the new row added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let name = String::from("cliux");
+    /// let count = 3;
+    /// let table = Table::new().row(vec![name, count.to_string()]).render();
+    /// assert_eq!(table, "+-------+---+\n| cliux | 3 |\n+-------+---+");
+    /// ```
+    pub fn row(mut self, row: impl IntoIterator<Item: AsRef<str>>) -> Self {
+        self.rows
+            .push(row.into_iter().map(|s| s.as_ref().to_string()).collect());
+        self
+    }
+
+    /// Adds several rows to the table at once.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. It accepts any nested collection of
+    /// strings, such as a `Vec<Vec<String>>` or an iterator of arrays, so
+    /// dynamic data doesn't need to be converted to slices first.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - An iterable of rows, where each row is an iterable of cells.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the new rows added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let users = [("alice", 3), ("bob", 12)];
+    /// let table = Table::new()
+    ///     .headers(&["User", "Posts"])
+    ///     .rows(users.iter().map(|(name, posts)| [name.to_string(), posts.to_string()]))
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-------+-------+\n\
+    ///      | User  | Posts |\n\
+    ///      +-------+-------+\n\
+    ///      | alice | 3     |\n\
+    ///      | bob   | 12    |\n\
+    ///      +-------+-------+"
+    /// );
+    /// ```
+    pub fn rows<R, C>(mut self, rows: R) -> Self
+    where
+        R: IntoIterator<Item = C>,
+        C: IntoIterator<Item: AsRef<str>>,
+    {
+        self.rows.extend(rows.into_iter().map(|row| {
+            row.into_iter()
+                .map(|cell| cell.as_ref().to_string())
+                .collect()
+        }));
+        self
+    }
+
+    /// Sets a footer row, drawn below the data rows after a separator.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. The footer suits totals and other
+    /// summaries, and sizes the columns just like the headers and rows do.
+    /// A footer with fewer cells than the table has columns is padded with
+    /// blank cells, and any extra cells are left out. Exports such as
+    /// `to_csv` contain only the headers and rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `footer` - The cells of the footer, such as a slice of string slices or a `Vec<String>`.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the footer set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let receipt = Table::new()
+    ///     .headers(&["Item", "Price"])
+    ///     .row(&["Coffee", "$8"])
+    ///     .row(&["Bagel", "$37"])
+    ///     .footer(&["Total", "$45"])
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     receipt,
+    ///     "+--------+-------+\n\
+    ///      | Item   | Price |\n\
+    ///      +--------+-------+\n\
+    ///      | Coffee | $8    |\n\
+    ///      | Bagel  | $37   |\n\
+    ///      +--------+-------+\n\
+    ///      | Total  | $45   |\n\
+    ///      +--------+-------+"
+    /// );
+    /// ```
+    pub fn footer(mut self, footer: impl IntoIterator<Item: AsRef<str>>) -> Self {
+        self.footer = Some(footer.into_iter().map(|s| s.as_ref().to_string()).collect());
+        self
+    }
+
+    /// Sets whether the footer text should be bold.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `bold` - A boolean indicating whether the footer should be bold (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated footer bold setting.
+    pub fn footer_bold(mut self, bold: bool) -> Self {
+        self.footer_bold = bold;
+        self
+    }
+
+    /// Adds a row whose cells may span several columns.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. Each cell is given as `(text, colspan)`.
+    /// A spanning cell is as wide as the columns it covers together with the
+    /// separators between them, and no vertical border is drawn inside it.
+    /// Spanning cells don't affect the widths of the columns they cover, so
+    /// long text wraps within the span. This suits summary rows such as
+    /// totals.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - A slice of `(text, colspan)` pairs, from left to right.
     ///
     /// # Returns
     ///
     /// The `Table` instance with the new row added.
-    pub fn row(mut self, row: &[&str]) -> Self {
-        self.rows.push(row.iter().map(|s| s.to_string()).collect());
+    ///
+    /// # Panics
+    ///
+    /// Panics if any colspan is zero, or if the headers or earlier rows
+    /// already set the number of columns and the colspans don't add up to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new()
+    ///     .headers(&["Item", "Qty", "Price"])
+    ///     .row(&["Coffee", "2", "$8"])
+    ///     .row(&["Bagel", "1", "$37"])
+    ///     .span_row(&[("Total", 2), ("$45", 1)])
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--------+-----+-------+\n\
+    ///      | Item   | Qty | Price |\n\
+    ///      +--------+-----+-------+\n\
+    ///      | Coffee | 2   | $8    |\n\
+    ///      | Bagel  | 1   | $37   |\n\
+    ///      | Total        | $45   |\n\
+    ///      +--------+-----+-------+"
+    /// );
+    ///
+    /// // Without headers, a spanning first row sets the column count
+    /// let table = Table::new().span_row(&[("Total", 2), ("$45", 1)]).row(&["a", "b", "c"]);
+    /// assert_eq!(table.render(), "+---+---+-----+\n| Total | $45 |\n| a | b | c   |\n+---+---+-----+");
+    /// ```
+    pub fn span_row(mut self, cells: &[(&str, usize)]) -> Self {
+        assert!(
+            cells.iter().all(|&(_, span)| span > 0),
+            "column spans must be at least 1"
+        );
+        let total: usize = cells.iter().map(|&(_, span)| span).sum();
+        let cols = self.col_count();
+        assert!(
+            cols == 0 || total == cols,
+            "column spans add up to {} but the table has {} columns",
+            total,
+            cols
+        );
+        self.spans.insert(
+            self.rows.len(),
+            cells.iter().map(|&(_, span)| span).collect(),
+        );
+        self.rows
+            .push(cells.iter().map(|&(text, _)| text.to_string()).collect());
+        self
+    }
+
+    /// Sorts the rows by the values in one column.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. When every non-empty value in the column
+    /// parses as a number, the rows are sorted numerically, and otherwise
+    /// lexicographically. Rows without a cell in the column sort as if the
+    /// cell were empty, which comes before any other value. A spanning cell
+    /// counts towards the first column it covers. Rows with equal values keep
+    /// their order, and the headers and footer are not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based index of the column to sort by.
+    /// * `ascending` - A boolean indicating whether to sort from smallest to largest (`true`) or the reverse (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with its rows sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new()
+    ///     .headers(&["Crate", "Downloads"])
+    ///     .row(&["serde", "900"])
+    ///     .row(&["cliux", "12"])
+    ///     .row(&["rand", "1500"])
+    ///     .sort_by_column(1, false)
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-------+-----------+\n\
+    ///      | Crate | Downloads |\n\
+    ///      +-------+-----------+\n\
+    ///      | rand  | 1500      |\n\
+    ///      | serde | 900       |\n\
+    ///      | cliux | 12        |\n\
+    ///      +-------+-----------+"
+    /// );
+    /// ```
+    pub fn sort_by_column(self, index: usize, ascending: bool) -> Self {
+        let keys: Vec<String> = self
+            .export_rows()
+            .into_iter()
+            .map(|mut fields| {
+                if index < fields.len() {
+                    fields.swap_remove(index)
+                } else {
+                    String::new()
+                }
+            })
+            .collect();
+        let numbers: Option<Vec<Option<f64>>> = keys
+            .iter()
+            .map(|key| match key.trim() {
+                "" => Some(None),
+                key => key.parse().ok().map(Some),
+            })
+            .collect();
+
+        let mut order: Vec<usize> = (0..keys.len()).collect();
+        order.sort_by(|&a, &b| {
+            let ordering = match numbers {
+                // `None` (an empty cell) sorts before every number
+                Some(ref numbers) => match (numbers[a], numbers[b]) {
+                    (Some(x), Some(y)) => x.total_cmp(&y),
+                    (x, y) => x.is_some().cmp(&y.is_some()),
+                },
+                None => keys[a].cmp(&keys[b]),
+            };
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+        self.reorder(&order)
+    }
+
+    /// Sorts the rows with a custom comparison function.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. The function is given the cells of two
+    /// rows as they were added. Rows that compare equal keep their order, and
+    /// the headers and footer are not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - A function returning the `Ordering` of two rows.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with its rows sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// // Sort by name length, shortest first
+    /// let table = Table::new()
+    ///     .row(&["serde"])
+    ///     .row(&["rand"])
+    ///     .sort_by(|a, b| a[0].len().cmp(&b[0].len()))
+    ///     .bordered(false)
+    ///     .render();
+    ///
+    /// assert_eq!(table, " rand  \n serde ");
+    /// ```
+    pub fn sort_by<F>(self, compare: F) -> Self
+    where
+        F: Fn(&[String], &[String]) -> Ordering,
+    {
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        order.sort_by(|&a, &b| compare(&self.rows[a], &self.rows[b]));
+        self.reorder(&order)
+    }
+
+    /// Rearranges the rows so that row `i` is the row previously at `order[i]`,
+    /// keeping any column spans attached to their rows.
+    fn reorder(mut self, order: &[usize]) -> Self {
+        let mut rows: Vec<Option<Vec<String>>> = self.rows.into_iter().map(Some).collect();
+        self.rows = order.iter().filter_map(|&old| rows[old].take()).collect();
+        let mut spans = std::mem::take(&mut self.spans);
+        for (new, old) in order.iter().enumerate() {
+            if let Some(row_spans) = spans.remove(old) {
+                self.spans.insert(new, row_spans);
+            }
+        }
         self
     }
 
@@ -137,7 +670,10 @@ impl Table {
     /// This method consumes `self` and returns a new `Table` instance,
     /// allowing for method chaining. The number of elements in `widths`
     /// should match the number of columns in the table. If content
-    /// exceeds a column's width, it will be wrapped.
+    /// exceeds a column's width, it will be wrapped onto extra lines, and
+    /// the whole row grows to the height of its tallest cell. Widths are
+    /// raised to at least 3, room for one character plus padding, so the
+    /// borders always line up no matter how narrow a column is.
     ///
     /// # Arguments
     ///
@@ -147,76 +683,1270 @@ impl Table {
     /// # Returns
     ///
     /// The `Table` instance with the custom column widths set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new()
+    ///     .headers(&["Name", "Notes"])
+    ///     .row(&["cliux", "a small crate for terminal output"])
+    ///     .widths(&[9, 14])
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+---------+--------------+\n\
+    ///      | Name    | Notes        |\n\
+    ///      +---------+--------------+\n\
+    ///      | cliux   | a small      |\n\
+    ///      |         | crate for    |\n\
+    ///      |         | terminal     |\n\
+    ///      |         | output       |\n\
+    ///      +---------+--------------+"
+    /// );
+    /// ```
+    ///
+    /// A column too narrow for its content still keeps the grid aligned:
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new().row(&["1", "cliux"]).widths(&[1, 5]).render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+---+-----+\n\
+    ///      | 1 | cli |\n\
+    ///      |   | ux  |\n\
+    ///      +---+-----+"
+    /// );
+    /// ```
     pub fn widths(mut self, widths: &[usize]) -> Self {
         self.widths = Some(widths.to_vec());
         self
     }
 
-    /// Prints the formatted table to the console.
+    /// Sets the horizontal alignment of each column.
     ///
-    /// This method constructs the table based on the configured headers,
-    /// rows, border setting, and column widths, then prints it to
-    /// standard output. Content will be padded or wrapped according to `widths`.
-    pub fn print(&self) {
-        let col_count = self
-            .headers
-            .as_ref()
-            .map_or_else(|| self.rows.first().map_or(0, |r| r.len()), |h| h.len());
-
-        // Auto-calculate column widths
-        let widths = self.widths.clone().unwrap_or_else(|| {
-            let mut max_widths = vec![0; col_count];
-            if let Some(ref headers) = self.headers {
-                for (i, h) in headers.iter().enumerate() {
-                    max_widths[i] = max_widths[i].max(h.len());
-                }
-            }
-            for row in &self.rows {
-                for (i, cell) in row.iter().enumerate() {
-                    max_widths[i] = max_widths[i].max(cell.len());
-                }
-            }
-            max_widths.iter().map(|w| w + 2).collect() // add padding
-        });
+    /// Alignments apply to both the header and the data cells of a column.
+    /// Columns without an alignment in the slice default to `Align::Left`.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `alignments` - A slice of `Align` values, one per column.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated column alignments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::{Align, Table};
+    ///
+    /// let table = Table::new()
+    ///     .headers(&["Item", "Qty"])
+    ///     .row(&["Apples", "3"])
+    ///     .row(&["Pears", "120"])
+    ///     .alignments(&[Align::Left, Align::Right])
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--------+-----+\n\
+    ///      | Item   | Qty |\n\
+    ///      +--------+-----+\n\
+    ///      | Apples |   3 |\n\
+    ///      | Pears  | 120 |\n\
+    ///      +--------+-----+"
+    /// );
+    /// ```
+    pub fn alignments(mut self, alignments: &[Align]) -> Self {
+        self.alignments = alignments.to_vec();
+        self
+    }
 
-        let draw_border = || {
-            if self.bordered {
-                print!("+");
-                for w in &widths {
-                    print!("{:-<1$}+", "", *w);
-                }
-                println!();
-            }
-        };
+    /// Marks columns that should never wrap.
+    ///
+    /// Columns listed here always size to their content, regardless of the
+    /// configured `widths`. Every other column keeps wrapping, sharing
+    /// whatever is left of the total configured width between them in
+    /// proportion to their requested widths. This is useful for ID or code
+    /// columns that become unreadable when broken across lines.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. It has no effect unless `widths` is set,
+    /// since auto-sized columns never wrap. If `max_width` later shrinks a
+    /// no-wrap column below its content, its cells are truncated with `…`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cols` - A slice of zero-based column indices that should not wrap.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated no-wrap columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// Table::new()
+    ///     .headers(&["ID", "Description"])
+    ///     .row(&["build-7f3a9c2e", "Compiles the workspace and runs every unit test."])
+    ///     .widths(&[10, 30])
+    ///     .no_wrap_cols(&[0])
+    ///     .print();
+    /// ```
+    pub fn no_wrap_cols(mut self, cols: &[usize]) -> Self {
+        self.no_wrap = cols.to_vec();
+        self
+    }
 
-        let draw_row = |row: &[String]| {
-            if self.bordered {
-                print!("|");
-            }
-            for (i, cell) in row.iter().enumerate() {
-                let padded = pad(cell, widths[i] - 2);
-                print!(" {} ", padded);
-                if self.bordered {
-                    print!("|");
-                } else if i < row.len() - 1 {
-                    print!(" ");
-                }
-            }
-            println!();
-        };
+    /// Sets whether over-long cells are truncated instead of wrapped.
+    ///
+    /// When enabled, a cell wider than its column is cut to fit and marked
+    /// with `…`, so every row stays one line tall and a single long value
+    /// can't stretch the table vertically.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `truncate` - A boolean indicating whether to truncate (`true`) or wrap (`false`) long cells.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated truncation setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new()
+    ///     .row(&["id", "a very long description"])
+    ///     .widths(&[4, 10])
+    ///     .truncate(true)
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+----+----------+\n\
+    ///      | id | a very … |\n\
+    ///      +----+----------+"
+    /// );
+    /// ```
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+        self
+    }
 
-        if let Some(ref headers) = self.headers {
-            draw_border();
-            draw_row(headers);
-            draw_border();
-        }
+    /// Renders a column of numeric series as sparklines.
+    ///
+    /// Each cell in the column is read as a list of numbers separated by
+    /// commas or whitespace (e.g. `"1, 4, 2, 8"`) and drawn with
+    /// `layout::sparkline`. Values that fail to parse are ignored. The header
+    /// of the column is left unchanged.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The zero-based index of the column to draw as sparklines.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the column marked for sparklines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new()
+    ///     .headers(&["Service", "Latency"])
+    ///     .row(&["api", "1, 2, 3, 4, 5, 6, 7, 8"])
+    ///     .sparkline_col(1)
+    ///     .render();
+    ///
+    /// assert!(table.contains("| api     | ▁▂▃▄▅▆▇█ |"));
+    /// ```
+    pub fn sparkline_col(mut self, col: usize) -> Self {
+        self.sparkline_cols.push(col);
+        self
+    }
 
-        for row in &self.rows {
-            draw_row(row);
-        }
+    /// Restructures the table into a two-column key/value view of a single row.
+    ///
+    /// The headers become the left column and the cells of the chosen row
+    /// become the right column, which reads better than a wide single-row
+    /// table for `show <id>` style output. Border settings are kept, while
+    /// column widths and per-column options are reset since the columns change.
+    /// Missing headers or cells are shown as empty.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero-based index of the data row to show.
+    ///
+    /// # Returns
+    ///
+    /// A new two-column `Table` holding the chosen row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new()
+    ///     .headers(&["Name", "Status"])
+    ///     .row(&["cliux", "active"])
+    ///     .detail_view(0)
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--------+--------+\n\
+    ///      | Name   | cliux  |\n\
+    ///      | Status | active |\n\
+    ///      +--------+--------+"
+    /// );
+    /// ```
+    pub fn detail_view(self, row: usize) -> Self {
+        let col_count = self.col_count();
+        let headers = self.headers.unwrap_or_default();
+        let cells = self.rows.get(row).cloned().unwrap_or_default();
 
-        if self.bordered {
-            draw_border();
-        }
+        let mut detail = Table::new().bordered(self.bordered);
+        detail.style = self.style;
+        detail.rows = (0..col_count)
+            .map(|i| {
+                vec![
+                    headers.get(i).cloned().unwrap_or_default(),
+                    cells.get(i).cloned().unwrap_or_default(),
+                ]
+            })
+            .collect();
+        detail
     }
+
+    /// Limits the total rendered width of the table, including borders.
+    ///
+    /// When the table would be wider than `width`, columns are shrunk in
+    /// proportion to their size and their content wraps. If the columns
+    /// can't shrink any further, columns are hidden from the right until the
+    /// table fits. Columns protected by `sticky_cols` are never shrunk or hidden.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The maximum total width of the table in characters.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated maximum width.
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Shrinks the table to fit the terminal when it would be wider.
+    ///
+    /// This is `max_width` with the width of the terminal, read when this
+    /// method is called. Columns shrink in proportion to their width, and
+    /// cell content wraps to fit. When standard output isn't a terminal,
+    /// the table keeps its natural width.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance limited to the terminal width.
+    pub fn fit_terminal(mut self) -> Self {
+        if let Some(width) = terminal_width() {
+            self.max_width = Some(width);
+        }
+        self
+    }
+
+    /// Protects the first `n` columns from shrinking or hiding.
+    ///
+    /// When the table is limited by `max_width`, the sticky columns keep their
+    /// full width and any reduction is applied only to the columns after
+    /// them. This keeps identifier columns readable in narrow terminals.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of leading columns to protect.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated sticky column count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new()
+    ///     .headers(&["Name", "Description"])
+    ///     .row(&["cliux-core", "Styled terminal output"])
+    ///     .max_width(30)
+    ///     .sticky_cols(1)
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------------+---------------+\n\
+    ///      | Name       | Description   |\n\
+    ///      +------------+---------------+\n\
+    ///      | cliux-core | Styled        |\n\
+    ///      |            | terminal      |\n\
+    ///      |            | output        |\n\
+    ///      +------------+---------------+"
+    /// );
+    /// ```
+    pub fn sticky_cols(mut self, n: usize) -> Self {
+        self.sticky = n;
+        self
+    }
+
+    /// Sets the border style for the table.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. Each border line uses the junction
+    /// glyphs matching its position, so the grid connects seamlessly.
+    ///
+    /// Supported styles:
+    /// - `"+"` (default): Uses `+`, `-`, and `|` for a plain ASCII grid.
+    /// - `"rounded"`: Uses `╭╮╰╯` corners with `┬┴├┤┼` junctions.
+    /// - `"square"`: Uses `┌┐└┘` corners with `┬┴├┤┼` junctions.
+    ///
+    /// If an unknown style is provided, it defaults to "square" borders.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - A string slice representing the desired border style.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated border style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new()
+    ///     .headers(&["A", "B", "C"])
+    ///     .row(&["1", "2", "3"])
+    ///     .style("rounded")
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "╭───┬───┬───╮\n\
+    ///      │ A │ B │ C │\n\
+    ///      ├───┼───┼───┤\n\
+    ///      │ 1 │ 2 │ 3 │\n\
+    ///      ╰───┴───┴───╯"
+    /// );
+    /// ```
+    pub fn style(mut self, style: &str) -> Self {
+        self.style = style.to_string();
+        self
+    }
+
+    /// Sets whether every other data row is shaded.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. The second, fourth, and following
+    /// even data rows get a dark gray background that spans the whole row,
+    /// including cell padding and the separators between cells, so the
+    /// stripes are continuous. The header is never striped. When color is
+    /// disabled by the `ColorMode`, striping has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `striped` - A boolean indicating whether to shade alternate rows (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated striping setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// Table::new()
+    ///     .headers(&["Day", "Visits"])
+    ///     .row(&["Mon", "120"])
+    ///     .row(&["Tue", "98"])
+    ///     .row(&["Wed", "143"])
+    ///     .striped(true)
+    ///     .header_bold(true)
+    ///     .print();
+    /// ```
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Sets the color of the header text.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. Accepts the same colors as `Label::color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired color.
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated header color.
+    pub fn header_color(mut self, color: &str) -> Self {
+        self.header_color = Some(color.to_string());
+        self
+    }
+
+    /// Sets whether the header text should be bold.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `bold` - A boolean indicating whether the header should be bold (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated header bold setting.
+    pub fn header_bold(mut self, bold: bool) -> Self {
+        self.header_bold = bold;
+        self
+    }
+
+    /// Sets whether cells are drawn without the space on either side of their text.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. Compact tables fit more columns in
+    /// dense output. Widths set with `widths` then cover the text only.
+    /// Without borders, columns are still separated by a single space. The
+    /// default is `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `compact` - A boolean indicating whether to drop the cell padding (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated compact setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new()
+    ///     .headers(&["ID", "Name"])
+    ///     .row(&["1", "cliux"])
+    ///     .compact(true)
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--+-----+\n\
+    ///      |ID|Name |\n\
+    ///      +--+-----+\n\
+    ///      |1 |cliux|\n\
+    ///      +--+-----+"
+    /// );
+    ///
+    /// let plain = Table::new().row(&["1", "cliux"]).compact(true).bordered(false).render();
+    /// assert_eq!(plain, "1 cliux");
+    /// ```
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Sets whether a horizontal rule is drawn between data rows.
+    ///
+    /// This method consumes `self` and returns a new `Table` instance,
+    /// allowing for method chaining. Separators make tall, wrapped rows
+    /// easier to tell apart. Bordered tables use the same line as the one
+    /// under the header. Tables without borders use a plain dashed line
+    /// spanning the full width. The default is `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `separators` - A boolean indicating whether to separate rows (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Table` instance with the updated row separator setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let table = Table::new()
+    ///     .row(&["a", "b"])
+    ///     .row(&["c", "d"])
+    ///     .row_separators(true)
+    ///     .render();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+---+---+\n\
+    ///      | a | b |\n\
+    ///      +---+---+\n\
+    ///      | c | d |\n\
+    ///      +---+---+"
+    /// );
+    /// ```
+    pub fn row_separators(mut self, separators: bool) -> Self {
+        self.row_separators = separators;
+        self
+    }
+
+    /// Renders the formatted table into a `String`.
+    ///
+    /// The output is identical to what `print` writes, with lines separated
+    /// by newlines and no trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered table.
+    pub fn render(&self) -> String {
+        let widths = self.fit_widths(self.column_widths());
+        let (tl, tr, bl, br, h, v) = get_border(&self.style);
+        let (top, bottom, left, right, cross) = get_junctions(&self.style);
+        let mut lines = Vec::new();
+
+        let border = |start: &str, join: &str, end: &str| {
+            let runs: Vec<String> = widths.iter().map(|w| h.repeat(*w)).collect();
+            format!("{}{}{}", start, runs.join(join), end)
+        };
+
+        // `text` styles each cell's text, while `fill` paints the whole row behind it
+        let draw_row = |lines: &mut Vec<String>,
+                        row: &[String],
+                        spans: &[usize],
+                        text: &Style,
+                        fill: &Style| {
+            // Each group is the first column of a cell and its total width, separators included
+            let groups = span_groups(spans, &widths);
+            // Rows shorter than the table get blank cells so every border is drawn
+            let cells: Vec<Vec<String>> = groups
+                .iter()
+                .enumerate()
+                .map(|(i, &(col, width))| {
+                    let cell = row.get(i).map_or("", |c| c.as_str());
+                    self.cell_lines(col, cell, width)
+                })
+                .collect();
+            let height = cells.iter().map(|c| c.len()).max().unwrap_or(1);
+
+            for line in 0..height {
+                let mut inner = String::new();
+                for (i, cell) in cells.iter().enumerate() {
+                    let (col, width) = groups[i];
+                    let content = cell.get(line).map_or("", |l| l.as_str());
+                    let align = self.alignments.get(col).copied().unwrap_or_default();
+                    let padded = pad_align(content, width - self.cell_padding(), align);
+                    if self.compact {
+                        inner.push_str(&paint(text, &padded));
+                    } else {
+                        inner.push_str(&format!(" {} ", paint(text, &padded)));
+                    }
+                    if i < cells.len() - 1 {
+                        inner.push_str(if self.bordered { v } else { " " });
+                    }
+                }
+                if self.bordered {
+                    lines.push(format!("{}{}{}", v, paint(fill, &inner), v));
+                } else {
+                    lines.push(paint(fill, &inner));
+                }
+            }
+        };
+
+        if self.bordered {
+            lines.push(border(tl, top, tr));
+        }
+
+        let plain = Style::new();
+        if let Some(ref headers) = self.headers {
+            let mut header_style = Style::new();
+            if let Some(colour) = self.header_color.as_deref().and_then(parse_colour) {
+                header_style = header_style.fg(colour);
+            }
+            if self.header_bold {
+                header_style = header_style.bold();
+            }
+            draw_row(&mut lines, headers, &[], &header_style, &plain);
+            if self.bordered {
+                lines.push(border(left, cross, right));
+            }
+        }
+
+        let separator = |lines: &mut Vec<String>| {
+            if self.bordered {
+                lines.push(border(left, cross, right));
+            } else {
+                let total = widths.iter().sum::<usize>() + widths.len().saturating_sub(1);
+                lines.push("-".repeat(total));
+            }
+        };
+
+        let stripe = Style::new().on(STRIPE);
+        for (i, row) in self.display_rows().iter().enumerate() {
+            if i > 0 && self.row_separators {
+                separator(&mut lines);
+            }
+            let fill = if self.striped && i % 2 == 1 {
+                &stripe
+            } else {
+                &plain
+            };
+            let spans = self.spans.get(&i).map_or(&[][..], |s| s.as_slice());
+            draw_row(&mut lines, row, spans, &plain, fill);
+        }
+
+        if let Some(ref footer) = self.footer {
+            separator(&mut lines);
+            let footer_style = if self.footer_bold {
+                Style::new().bold()
+            } else {
+                plain
+            };
+            draw_row(&mut lines, footer, &[], &footer_style, &plain);
+        }
+
+        if self.bordered {
+            lines.push(border(bl, bottom, br));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Prints the formatted table to the console.
+    ///
+    /// This method constructs the table based on the configured headers,
+    /// rows, border setting, and column widths, then prints it to
+    /// standard output. Content will be padded or wrapped according to `widths`.
+    pub fn print(&self) {
+        Render::print(self)
+    }
+
+    /// Exports the table as comma-separated values.
+    ///
+    /// The headers, if set, form the first record, followed by one record per
+    /// row. No styling, wrapping, or sparklines are applied. Fields containing
+    /// commas, double quotes, or line breaks are quoted as described in
+    /// RFC 4180, with inner quotes doubled. The headers and short rows are
+    /// padded with empty fields to the column count, and a spanning cell fills its first column
+    /// and leaves the columns it covers empty. Records are separated by
+    /// `\r\n`, with no trailing line break.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the CSV records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let csv = Table::new()
+    ///     .headers(&["Name", "Quote"])
+    ///     .row(&["Ada", "Hello, \"world\""])
+    ///     .row(&["Bob"])
+    ///     .to_csv();
+    ///
+    /// assert_eq!(csv, "Name,Quote\r\nAda,\"Hello, \"\"world\"\"\"\r\nBob,");
+    ///
+    /// let csv = Table::new().headers(&["Name"]).row(&["Ada", "extra"]).to_csv();
+    /// assert_eq!(csv, "Name,\r\nAda,extra");
+    /// ```
+    pub fn to_csv(&self) -> String {
+        self.export_records()
+            .iter()
+            .map(|record| {
+                record
+                    .iter()
+                    .map(|field| {
+                        if field.contains([',', '"', '\n', '\r']) {
+                            format!("\"{}\"", field.replace('"', "\"\""))
+                        } else {
+                            field.clone()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect::<Vec<_>>()
+            .join("\r\n")
+    }
+
+    /// Exports the table as tab-separated values.
+    ///
+    /// Records are laid out as in `to_csv`, with fields separated by tabs and
+    /// records by `\n`. Since TSV has no quoting, backslashes, tabs, and line
+    /// breaks inside fields are escaped as `\\`, `\t`, `\n`, and `\r`.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the TSV records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let tsv = Table::new()
+    ///     .headers(&["Name", "Note"])
+    ///     .row(&["Ada", "line one\nline two"])
+    ///     .to_tsv();
+    ///
+    /// assert_eq!(tsv, "Name\tNote\nAda\tline one\\nline two");
+    /// ```
+    pub fn to_tsv(&self) -> String {
+        self.export_records()
+            .iter()
+            .map(|record| {
+                record
+                    .iter()
+                    .map(|field| {
+                        field
+                            .replace('\\', "\\\\")
+                            .replace('\t', "\\t")
+                            .replace('\n', "\\n")
+                            .replace('\r', "\\r")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Exports the table as a GitHub-flavored Markdown table.
+    ///
+    /// The output has a header row, a delimiter row, and one row per data
+    /// row. Markdown requires a header row, so a table without headers gets
+    /// an empty one. Columns with an alignment set through `alignments` are
+    /// marked `:---`, `:---:`, or `---:`, and the rest `---`. Pipes in cells
+    /// are escaped as `\|` and line breaks become `<br>`. The header row and
+    /// short rows are padded with empty cells to the column count, and an
+    /// empty table gets a single empty column.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the Markdown table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::{Align, Table};
+    ///
+    /// let markdown = Table::new()
+    ///     .headers(&["Flag", "Meaning"])
+    ///     .row(&["-v", "verbose | debug"])
+    ///     .alignments(&[Align::Left, Align::Right])
+    ///     .to_markdown();
+    ///
+    /// assert_eq!(
+    ///     markdown,
+    ///     "| Flag | Meaning |\n\
+    ///      | :--- | ---: |\n\
+    ///      | -v | verbose \\| debug |"
+    /// );
+    /// ```
+    ///
+    /// A row longer than the headers gets a blank header cell:
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let markdown = Table::new()
+    ///     .headers(&["Name"])
+    ///     .row(&["Ada", "extra"])
+    ///     .to_markdown();
+    /// assert_eq!(markdown, "| Name |  |\n| --- | --- |\n| Ada | extra |");
+    ///
+    /// assert_eq!(Table::new().to_markdown(), "|  |\n| --- |");
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let cols = self.col_count().max(1);
+        let format_row = |cells: &[String]| {
+            let cells: Vec<String> = cells
+                .iter()
+                .map(|c| {
+                    c.replace('|', "\\|")
+                        .replace("\r\n", "<br>")
+                        .replace('\n', "<br>")
+                })
+                .collect();
+            format!("| {} |", cells.join(" | "))
+        };
+
+        let mut headers = self.export_headers().unwrap_or_default();
+        headers.resize(cols, String::new());
+        let delimiters: Vec<String> = (0..cols)
+            .map(|i| {
+                match self.alignments.get(i) {
+                    Some(Align::Left) => ":---",
+                    Some(Align::Center) => ":---:",
+                    Some(Align::Right) => "---:",
+                    None => "---",
+                }
+                .to_string()
+            })
+            .collect();
+
+        let mut lines = vec![
+            format_row(&headers),
+            format!("| {} |", delimiters.join(" | ")),
+        ];
+        lines.extend(self.export_rows().iter().map(|row| format_row(row)));
+        lines.join("\n")
+    }
+
+    /// Exports the table as JSON.
+    ///
+    /// With headers, the rows become an array of objects keyed by header,
+    /// with keys in column order. A header that repeats an earlier one gets
+    /// a numeric suffix (`"Name"`, `"Name_2"`, ...), so no cell is lost.
+    /// Columns past the last header get blank keys (`""`, `"_2"`, ...).
+    /// Without headers, the rows become an array of arrays. Short rows are
+    /// padded with empty strings to the column count.
+    ///
+    /// Only available with the `serde` feature.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the JSON array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let json = Table::new()
+    ///     .headers(&["Name", "Age"])
+    ///     .row(&["Ada", "36"])
+    ///     .to_json();
+    /// assert_eq!(json, r#"[{"Name":"Ada","Age":"36"}]"#);
+    ///
+    /// let json = Table::new().row(&["a", "b"]).to_json();
+    /// assert_eq!(json, r#"[["a","b"]]"#);
+    ///
+    /// let json = Table::new()
+    ///     .headers(&["a"])
+    ///     .row(&["x", "y", "z"])
+    ///     .to_json();
+    /// assert_eq!(json, r#"[{"a":"x","":"y","_2":"z"}]"#);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        use serde_json::{Map, Value};
+
+        let rows = self.export_rows();
+        let value = match self.export_headers() {
+            Some(headers) => {
+                let keys = unique_keys(&headers);
+                Value::Array(
+                    rows.into_iter()
+                        .map(|row| {
+                            let object: Map<String, Value> = keys
+                                .iter()
+                                .cloned()
+                                .zip(row.into_iter().map(Value::String))
+                                .collect();
+                            Value::Object(object)
+                        })
+                        .collect(),
+                )
+            }
+            None => serde_json::json!(rows),
+        };
+        value.to_string()
+    }
+
+    /// Builds a table from a JSON array of objects, such as an API response.
+    ///
+    /// The headers are every key found in the objects, in the order they
+    /// are first seen, and each object becomes one row. A key missing from
+    /// an object gives an empty cell. Strings are shown without quotes,
+    /// `null` as an empty cell, and numbers, booleans, arrays, and nested
+    /// objects as compact JSON. An empty array gives an empty table.
+    ///
+    /// Only available with the `serde` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The JSON array to convert.
+    ///
+    /// # Returns
+    ///
+    /// The new `Table`, or an error message if `value` is not an array or
+    /// any element is not an object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Table;
+    ///
+    /// let users = serde_json::json!([
+    ///     {"name": "Ada", "age": 36},
+    ///     {"name": "Bob", "tags": ["admin"]},
+    /// ]);
+    /// let table = Table::from_json(&users).unwrap().render();
+    /// assert_eq!(
+    ///     table,
+    ///     "+------+-----+-----------+\n\
+    ///      | name | age | tags      |\n\
+    ///      +------+-----+-----------+\n\
+    ///      | Ada  | 36  |           |\n\
+    ///      | Bob  |     | [\"admin\"] |\n\
+    ///      +------+-----+-----------+"
+    /// );
+    ///
+    /// assert!(Table::from_json(&serde_json::json!({"name": "Ada"})).is_err());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json(value: &serde_json::Value) -> Result<Table, String> {
+        use serde_json::Value;
+
+        let items = value
+            .as_array()
+            .ok_or_else(|| "expected a JSON array of objects".to_string())?;
+        let objects = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                item.as_object()
+                    .ok_or_else(|| format!("element {} of the JSON array is not an object", i))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if objects.is_empty() {
+            return Ok(Table::new());
+        }
+
+        let mut headers: Vec<String> = Vec::new();
+        for key in objects.iter().flat_map(|object| object.keys()) {
+            if !headers.contains(key) {
+                headers.push(key.clone());
+            }
+        }
+        let cell = |value: Option<&Value>| match value {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(text)) => text.clone(),
+            Some(other) => other.to_string(),
+        };
+        let rows: Vec<Vec<String>> = objects
+            .iter()
+            .map(|object| headers.iter().map(|key| cell(object.get(key))).collect())
+            .collect();
+        Ok(Table::from(rows).headers(headers))
+    }
+
+    /// Returns the headers, if any, followed by the rows, each with one field per column.
+    fn export_records(&self) -> Vec<Vec<String>> {
+        let mut records: Vec<Vec<String>> = self.export_headers().into_iter().collect();
+        records.extend(self.export_rows());
+        records
+    }
+
+    /// Returns the headers, if any, padded with empty strings to the column count.
+    fn export_headers(&self) -> Option<Vec<String>> {
+        let cols = self.col_count();
+        self.headers.as_ref().map(|headers| {
+            let mut headers = headers.clone();
+            if headers.len() < cols {
+                headers.resize(cols, String::new());
+            }
+            headers
+        })
+    }
+
+    /// Returns the unstyled rows, with spanning cells placed in their first column
+    /// and every row padded to the column count.
+    fn export_rows(&self) -> Vec<Vec<String>> {
+        let cols = self.col_count();
+        self.rows
+            .iter()
+            .enumerate()
+            .map(|(r, row)| {
+                let mut fields = match self.spans.get(&r) {
+                    Some(spans) => {
+                        let mut fields = Vec::new();
+                        for (cell, &span) in row.iter().zip(spans) {
+                            fields.push(cell.clone());
+                            fields.extend(std::iter::repeat_n(String::new(), span - 1));
+                        }
+                        fields
+                    }
+                    None => row.clone(),
+                };
+                if fields.len() < cols {
+                    fields.resize(cols, String::new());
+                }
+                fields
+            })
+            .collect()
+    }
+
+    /// Returns the number of columns: the most cells in the headers, footer,
+    /// or any row, counting a spanning cell once per column it covers.
+    fn col_count(&self) -> usize {
+        let row_widths = self.rows.iter().enumerate().map(|(r, row)| {
+            self.spans
+                .get(&r)
+                .map_or(row.len(), |spans| spans.iter().sum())
+        });
+        self.headers
+            .iter()
+            .chain(&self.footer)
+            .map(|cells| cells.len())
+            .chain(row_widths)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Calculates the width of each column to fit its content, including padding.
+    fn natural_widths(&self) -> Vec<usize> {
+        let mut max_widths = vec![0; self.col_count()];
+        if let Some(ref headers) = self.headers {
+            for (i, h) in headers.iter().enumerate() {
+                max_widths[i] = max_widths[i].max(visible_width(h));
+            }
+        }
+        if let Some(ref footer) = self.footer {
+            for (width, cell) in max_widths.iter_mut().zip(footer) {
+                *width = (*width).max(visible_width(cell));
+            }
+        }
+        for (r, row) in self.display_rows().iter().enumerate() {
+            let Some(spans) = self.spans.get(&r) else {
+                for (i, cell) in row.iter().enumerate() {
+                    max_widths[i] = max_widths[i].max(visible_width(cell));
+                }
+                continue;
+            };
+            // Spanning cells share the width of several columns, so they don't size any one
+            let mut col = 0;
+            for (cell, &span) in row.iter().zip(spans) {
+                if span == 1 && col < max_widths.len() {
+                    max_widths[col] = max_widths[col].max(visible_width(cell));
+                }
+                col += span;
+            }
+        }
+        max_widths.iter().map(|w| w + self.cell_padding()).collect() // add padding
+    }
+
+    /// Resolves the final column widths, including padding.
+    ///
+    /// Without explicit `widths`, every column sizes to its content. Otherwise
+    /// no-wrap columns take their natural width and the wrapping columns split
+    /// the rest of the configured total in proportion to their requested widths.
+    fn column_widths(&self) -> Vec<usize> {
+        let natural = self.natural_widths();
+        let Some(ref requested) = self.widths else {
+            return natural;
+        };
+        // Narrower columns couldn't hold their padding, so raise them to the minimum
+        let requested: Vec<usize> = requested
+            .iter()
+            .map(|w| (*w).max(self.min_col_width()))
+            .collect();
+        if self.no_wrap.is_empty() {
+            return requested;
+        }
+
+        let is_fixed = |i: usize| self.no_wrap.contains(&i) && i < natural.len();
+        let budget: usize = requested.iter().sum();
+        let fixed: usize = (0..requested.len())
+            .filter(|&i| is_fixed(i))
+            .map(|i| natural[i])
+            .sum();
+        let wrapped_requested: usize = (0..requested.len())
+            .filter(|&i| !is_fixed(i))
+            .map(|i| requested[i])
+            .sum();
+        let remaining = budget.saturating_sub(fixed);
+
+        (0..requested.len())
+            .map(|i| {
+                if is_fixed(i) {
+                    natural[i]
+                } else {
+                    (remaining * requested[i] / wrapped_requested.max(1)).max(self.min_col_width())
+                }
+            })
+            .collect()
+    }
+
+    /// Shrinks or hides columns so the table fits within `max_width`.
+    ///
+    /// Sticky columns keep their width. The rest shrink in proportion to their
+    /// width down to a minimum, after which columns are dropped from the right.
+    fn fit_widths(&self, mut widths: Vec<usize>) -> Vec<usize> {
+        let Some(max) = self.max_width else {
+            return widths;
+        };
+        let sticky = self.sticky.min(widths.len());
+
+        loop {
+            let cols = widths.len();
+            let separators = if self.bordered {
+                cols + 1
+            } else {
+                cols.saturating_sub(1)
+            };
+            let budget = max.saturating_sub(separators);
+            if widths.iter().sum::<usize>() <= budget {
+                return widths;
+            }
+
+            let flexible = cols - sticky;
+            let available = budget.saturating_sub(widths[..sticky].iter().sum());
+            if flexible > 0 && available >= flexible * self.min_col_width() {
+                let flexible_total: usize = widths[sticky..].iter().sum();
+                for w in &mut widths[sticky..] {
+                    *w = (*w * available / flexible_total).max(self.min_col_width());
+                }
+                // Raising columns to the minimum can overshoot; take it back from the widest
+                while widths[sticky..].iter().sum::<usize>() > available {
+                    if let Some(widest) = widths[sticky..].iter_mut().max() {
+                        *widest -= 1;
+                    }
+                }
+                return widths;
+            }
+
+            if flexible == 0 || cols == 1 {
+                return widths;
+            }
+            widths.pop();
+        }
+    }
+
+    /// Returns the data rows as they are displayed, with sparkline columns drawn.
+    fn display_rows(&self) -> Vec<Vec<String>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(i, cell)| {
+                        if self.sparkline_cols.contains(&i) {
+                            let values: Vec<f64> = cell
+                                .split(|c: char| c == ',' || c.is_whitespace())
+                                .filter_map(|v| v.parse().ok())
+                                .collect();
+                            sparkline(&values)
+                        } else {
+                            cell.clone()
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the number of spaces around the text of each cell.
+    fn cell_padding(&self) -> usize {
+        if self.compact { 0 } else { 2 }
+    }
+
+    /// Returns the narrowest a column may be: room for one character plus padding.
+    fn min_col_width(&self) -> usize {
+        1 + self.cell_padding()
+    }
+
+    /// Splits a cell into the physical lines it occupies in a column of `width`.
+    fn cell_lines(&self, col: usize, cell: &str, width: usize) -> Vec<String> {
+        let room = width.saturating_sub(self.cell_padding());
+        if visible_width(cell) <= room {
+            return vec![cell.to_string()];
+        }
+        // No-wrap columns only end up too narrow when shrunk to fit, so cut them instead
+        if self.truncate || self.no_wrap.contains(&col) {
+            return vec![truncate(cell, room)];
+        }
+        let lines = hard_wrap(cell, room);
+        if lines.is_empty() {
+            vec![String::new()]
+        } else {
+            lines
+        }
+    }
+}
+
+impl Render for Table {
+    fn render(&self) -> String {
+        Table::render(self)
+    }
+}
+
+/// Groups columns into cells according to `spans`, returning each cell's first
+/// column and total width.
+///
+/// An empty `spans` gives one cell per column. A spanning cell's width includes
+/// the separators between the columns it covers. Spans reaching past the last
+/// column, such as when columns are hidden to fit `max_width`, are cut short.
+fn span_groups(spans: &[usize], widths: &[usize]) -> Vec<(usize, usize)> {
+    if spans.is_empty() {
+        return widths.iter().copied().enumerate().collect();
+    }
+    let mut groups = Vec::new();
+    let mut col = 0;
+    for &span in spans {
+        if col >= widths.len() {
+            break;
+        }
+        let end = (col + span).min(widths.len());
+        let width = widths[col..end].iter().sum::<usize>() + (end - col - 1);
+        groups.push((col, width));
+        col = end;
+    }
+    groups
+}
+
+/// Makes repeated keys unique by suffixing later copies with `_2`, `_3`, and so on.
+#[cfg(feature = "serde")]
+pub(crate) fn unique_keys(keys: &[String]) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    keys.iter()
+        .map(|key| {
+            let mut unique = key.clone();
+            let mut n = 1;
+            while !seen.insert(unique.clone()) {
+                n += 1;
+                unique = format!("{}_{}", key, n);
+            }
+            unique
+        })
+        .collect()
 }
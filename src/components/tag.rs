@@ -1,7 +1,13 @@
+use crate::components::Render;
+use crate::layout::display_width;
+use crate::style::{hyperlink, paint};
+use std::fmt;
+
 /// A component for displaying styled text tags in the terminal.
 ///
 /// The `Tag` struct allows you to create short, enclosed text snippets
-/// with customizable wrappers (e.g., square brackets, parentheses, curly braces),
+/// with customizable wrappers (e.g., square brackets, parentheses, curly braces,
+/// angle brackets, custom strings, or none at all),
 /// color, and boldness. Tags are useful for categorization, status indicators,
 /// or drawing attention to specific keywords.
 ///
@@ -39,7 +45,12 @@ pub struct Tag {
     text: String,
     wrapper: (String, String), // e.g. ("(", ")")
     color: Option<String>,
+    bg: Option<String>,
+    link: Option<String>,
     bold: bool,
+    italic: bool,
+    underline: bool,
+    dim: bool,
 }
 
 impl Tag {
@@ -55,12 +66,17 @@ impl Tag {
     /// # Returns
     ///
     /// A new `Tag` instance.
-    pub fn new(text: &str) -> Self {
+    pub fn new(text: impl Into<String>) -> Self {
         Self {
-            text: text.to_string(),
+            text: text.into(),
             wrapper: ("[".to_string(), "]".to_string()),
             color: None,
+            bg: None,
+            link: None,
             bold: false,
+            italic: false,
+            underline: false,
+            dim: false,
         }
     }
 
@@ -90,6 +106,68 @@ impl Tag {
         self
     }
 
+    /// Sets the tag's wrappers to angle brackets `<>`.
+    ///
+    /// This method consumes `self` and returns a new `Tag` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Returns
+    ///
+    /// The `Tag` instance with angle bracket wrappers.
+    pub fn angled(mut self) -> Self {
+        self.wrapper = ("<".to_string(), ">".to_string());
+        self
+    }
+
+    /// Removes the tag's wrappers, leaving only the styled text.
+    ///
+    /// This method consumes `self` and returns a new `Tag` instance,
+    /// allowing for method chaining. Color, background, and text styles
+    /// still apply, which suits color-only pills.
+    ///
+    /// # Returns
+    ///
+    /// The `Tag` instance with no wrappers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Tag;
+    ///
+    /// assert_eq!(Tag::new("ok").none().color("green").inline(), "ok");
+    /// ```
+    pub fn none(mut self) -> Self {
+        self.wrapper = (String::new(), String::new());
+        self
+    }
+
+    /// Sets custom strings to open and close the tag.
+    ///
+    /// This method consumes `self` and returns a new `Tag` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `open` - The string slice printed before the text.
+    /// * `close` - The string slice printed after the text.
+    ///
+    /// # Returns
+    ///
+    /// The `Tag` instance with the custom wrappers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Tag;
+    ///
+    /// assert_eq!(Tag::new("v1.2").wrappers("<", ">").inline(), "<v1.2>");
+    /// assert_eq!(Tag::new("beta").wrappers("« ", " »").inline(), "« beta »");
+    /// ```
+    pub fn wrappers(mut self, open: impl Into<String>, close: impl Into<String>) -> Self {
+        self.wrapper = (open.into(), close.into());
+        self
+    }
+
     /// Sets the color of the tag's text.
     ///
     /// This method consumes `self` and returns a new `Tag` instance,
@@ -100,16 +178,48 @@ impl Tag {
     /// # Arguments
     ///
     /// * `color` - A string slice representing the desired color name
-    ///   (e.g., "red", "blue", "green").
+    ///   (e.g., "red", "blue", "green"), a hex string (e.g., `"#ff8800"`),
+    ///   or a 256-color index (e.g., `"208"` or `"color208"`).
     ///
     /// # Returns
     ///
     /// The `Tag` instance with the updated color.
+    ///
+    /// # Examples
+    ///
+    /// ```standalone_crate
+    /// use cliux::{ColorMode, Tag, set_color_mode};
+    ///
+    /// set_color_mode(ColorMode::Always);
+    /// assert_eq!(
+    ///     Tag::new("X").color("#ff8800").inline(),
+    ///     "\x1b[38;2;255;136;0m[X]\x1b[0m"
+    /// );
+    /// ```
     pub fn color(mut self, color: &str) -> Self {
         self.color = Some(color.to_string());
         self
     }
 
+    /// Sets the background color of the tag, including its wrappers.
+    ///
+    /// This method consumes `self` and returns a new `Tag` instance,
+    /// allowing for method chaining. Accepts the same color names, hex
+    /// strings, and 256-color indices as `color`. Like all styling, the
+    /// background is dropped when color output is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string slice representing the desired background color.
+    ///
+    /// # Returns
+    ///
+    /// The `Tag` instance with the updated background color.
+    pub fn bg(mut self, color: &str) -> Self {
+        self.bg = Some(color.to_string());
+        self
+    }
+
     /// Sets whether the tag's text should be bold.
     ///
     /// This method consumes `self` and returns a new `Tag` instance,
@@ -127,6 +237,80 @@ impl Tag {
         self
     }
 
+    /// Sets whether the tag's text should be italic.
+    ///
+    /// This method consumes `self` and returns a new `Tag` instance,
+    /// allowing for method chaining. It combines with the other text styles,
+    /// such as `bold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `italic` - A boolean indicating whether the text should be italicized (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Tag` instance with the updated italic setting.
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    /// Sets whether the tag's text should be underlined.
+    ///
+    /// This method consumes `self` and returns a new `Tag` instance,
+    /// allowing for method chaining. It combines with the other text styles,
+    /// such as `bold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `underline` - A boolean indicating whether the text should be underlined (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Tag` instance with the updated underline setting.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the tag's text should be dimmed.
+    ///
+    /// This method consumes `self` and returns a new `Tag` instance,
+    /// allowing for method chaining. It combines with the other text styles,
+    /// such as `bold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - A boolean indicating whether the text should be dimmed (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Tag` instance with the updated dim setting.
+    pub fn dim(mut self, dim: bool) -> Self {
+        self.dim = dim;
+        self
+    }
+
+    /// Makes the tag a clickable hyperlink to `url`.
+    ///
+    /// This method consumes `self` and returns a new `Tag` instance,
+    /// allowing for method chaining. The styled text is wrapped in an OSC 8
+    /// escape sequence, which supporting terminals display as a link. The
+    /// sequence is omitted when color output is disabled, so piped output
+    /// shows just the text.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The address the link points to.
+    ///
+    /// # Returns
+    ///
+    /// The `Tag` instance with the link set.
+    pub fn link(mut self, url: impl Into<String>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
+
     /// Returns the styled tag as a `String`, including its wrappers.
     ///
     /// This method formats the tag's text with the applied color and bold settings,
@@ -140,18 +324,59 @@ impl Tag {
         use ansi_term::Style;
 
         let mut style = Style::new();
-        if let Some(ref color) = self.color {
-            if let Some(colour) = super::label::parse_colour(color) {
-                style = style.fg(colour);
-            }
+        if let Some(ref color) = self.color
+            && let Some(colour) = super::label::parse_colour(color)
+        {
+            style = style.fg(colour);
+        }
+        if let Some(ref color) = self.bg
+            && let Some(colour) = super::label::parse_colour(color)
+        {
+            style = style.on(colour);
         }
         if self.bold {
             style = style.bold();
         }
+        if self.italic {
+            style = style.italic();
+        }
+        if self.underline {
+            style = style.underline();
+        }
+        if self.dim {
+            style = style.dimmed();
+        }
 
-        style
-            .paint(format!("{}{}{}", self.wrapper.0, self.text, self.wrapper.1))
-            .to_string()
+        let styled = paint(
+            &style,
+            &format!("{}{}{}", self.wrapper.0, self.text, self.wrapper.1),
+        );
+        match self.link {
+            Some(ref url) => hyperlink(url, &styled),
+            None => styled,
+        }
+    }
+
+    /// Returns the number of terminal columns the tag occupies.
+    ///
+    /// Unlike the `len()` of `inline()`, this ignores ANSI escape codes and
+    /// counts the display width of the text and its wrappers, so it can be
+    /// used to line tags up in columns.
+    ///
+    /// # Returns
+    ///
+    /// The display width of the wrapped text, e.g. `[TEXT]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Tag;
+    ///
+    /// assert_eq!(Tag::new("beta").bold(true).visible_width(), 6);
+    /// assert_eq!(Tag::new("beta").none().visible_width(), 4);
+    /// ```
+    pub fn visible_width(&self) -> usize {
+        display_width(&self.wrapper.0) + display_width(&self.text) + display_width(&self.wrapper.1)
     }
 
     /// Prints the styled tag to the console, including its wrappers.
@@ -159,6 +384,29 @@ impl Tag {
     /// This method formats the tag's text with the applied color and bold settings
     /// and prints it to standard output, e.g., `[TAG TEXT]`.
     pub fn print(&self) {
-        println!("{}", self.inline());
+        Render::print(self)
+    }
+}
+
+impl Render for Tag {
+    fn render(&self) -> String {
+        self.inline()
+    }
+}
+
+impl fmt::Display for Tag {
+    /// Writes the same styled text as `inline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cliux::Tag;
+    ///
+    /// let status = Tag::new("OK").color("green");
+    /// println!("Status: {}", status);
+    /// assert_eq!(status.to_string(), status.inline());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.inline())
     }
 }
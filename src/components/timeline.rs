@@ -0,0 +1,239 @@
+use crate::components::Render;
+use crate::components::label::parse_colour;
+use crate::layout::wrap_text;
+use crate::style::{paint, unicode_enabled};
+use ansi_term::Style;
+
+/// The progress of an event in a `Timeline`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventStatus {
+    /// The event has completed. Drawn with a green node.
+    Done,
+    /// The event is in progress. Drawn with a yellow node.
+    Active,
+    /// The event hasn't started yet. Drawn with a hollow node.
+    Pending,
+}
+
+/// A vertical list of sequential events joined by connector lines.
+///
+/// The `Timeline` struct is useful for showing deploy steps, job stages, or
+/// history. Each event is drawn as a node (`●`) followed by its label and an
+/// optional timestamp, with `│` connectors between consecutive events. Nodes
+/// are colored by status: green for done, yellow for in progress, and hollow
+/// (`○`) for pending.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::Timeline;
+///
+/// Timeline::new()
+///     .event("Built", "12:01")
+///     .event("Tested", "12:03")
+///     .active("Deploying", "12:05")
+///     .pending("Verify", "")
+///     .print();
+/// ```
+pub struct Timeline {
+    events: Vec<(String, String, EventStatus)>,
+    width: Option<usize>,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timeline {
+    /// Creates a new, empty `Timeline` instance.
+    ///
+    /// By default, labels are not wrapped unless a `width` is explicitly set.
+    ///
+    /// # Returns
+    ///
+    /// A new `Timeline` instance.
+    pub fn new() -> Self {
+        Self {
+            events: Vec::new(),
+            width: None,
+        }
+    }
+
+    /// Adds a completed event to the timeline.
+    ///
+    /// This method consumes `self` and returns a new `Timeline` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A string slice describing the event.
+    /// * `time` - A timestamp shown after the label, or `""` to omit it.
+    ///
+    /// # Returns
+    ///
+    /// The `Timeline` instance with the event added.
+    pub fn event(self, label: impl Into<String>, time: impl Into<String>) -> Self {
+        self.push(label, time, EventStatus::Done)
+    }
+
+    /// Adds an in-progress event to the timeline.
+    ///
+    /// This method consumes `self` and returns a new `Timeline` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A string slice describing the event.
+    /// * `time` - A timestamp shown after the label, or `""` to omit it.
+    ///
+    /// # Returns
+    ///
+    /// The `Timeline` instance with the event added.
+    pub fn active(self, label: impl Into<String>, time: impl Into<String>) -> Self {
+        self.push(label, time, EventStatus::Active)
+    }
+
+    /// Adds a pending event to the timeline.
+    ///
+    /// This method consumes `self` and returns a new `Timeline` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A string slice describing the event.
+    /// * `time` - A timestamp shown after the label, or `""` to omit it.
+    ///
+    /// # Returns
+    ///
+    /// The `Timeline` instance with the event added.
+    pub fn pending(self, label: impl Into<String>, time: impl Into<String>) -> Self {
+        self.push(label, time, EventStatus::Pending)
+    }
+
+    /// Adds an event with an explicit status to the timeline.
+    ///
+    /// This method consumes `self` and returns a new `Timeline` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A string slice describing the event.
+    /// * `time` - A timestamp shown after the label, or `""` to omit it.
+    /// * `status` - The `EventStatus` that decides how the node is drawn.
+    ///
+    /// # Returns
+    ///
+    /// The `Timeline` instance with the event added.
+    pub fn push(
+        mut self,
+        label: impl Into<String>,
+        time: impl Into<String>,
+        status: EventStatus,
+    ) -> Self {
+        self.events.push((label.into(), time.into(), status));
+        self
+    }
+
+    /// Sets the maximum width of each event, enabling label wrapping.
+    ///
+    /// Wrapped lines continue under the label, with the connector line
+    /// drawn alongside them so the chain stays unbroken.
+    ///
+    /// This method consumes `self` and returns a new `Timeline` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The maximum desired width for each event in characters.
+    ///
+    /// # Returns
+    ///
+    /// The `Timeline` instance with the updated width setting.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Renders the timeline into a `String`.
+    ///
+    /// Each event is drawn as a colored node with its label and timestamp,
+    /// and consecutive events are joined by a `│` connector line. Lines are
+    /// separated by newlines, with no trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered timeline.
+    pub fn render(&self) -> String {
+        let mut out = Vec::new();
+        // Width of the node and its "── " branch
+        let indent = 4;
+        let (done, pending, branch, connector) = if unicode_enabled() {
+            ("●", "○", "──", "│")
+        } else {
+            ("*", "o", "--", "|")
+        };
+
+        for (i, (label, time, status)) in self.events.iter().enumerate() {
+            let is_last = i == self.events.len() - 1;
+            let (node, color) = match status {
+                EventStatus::Done => (done, Some("green")),
+                EventStatus::Active => (done, Some("yellow")),
+                EventStatus::Pending => (pending, None),
+            };
+
+            let mut style = Style::new();
+            if let Some(colour) = color.and_then(parse_colour) {
+                style = style.fg(colour);
+            }
+
+            let lines = match self.width {
+                Some(w) => wrap_text(label, w.saturating_sub(indent)),
+                None => vec![label.clone()],
+            };
+
+            for (j, line) in lines.iter().enumerate() {
+                if j == 0 {
+                    let stamp = if time.is_empty() {
+                        String::new()
+                    } else {
+                        format!("  {}", paint(&Style::new().dimmed(), time))
+                    };
+                    out.push(format!(
+                        "{}{} {}{}",
+                        paint(&style, node),
+                        branch,
+                        line,
+                        stamp
+                    ));
+                } else {
+                    let rail = if is_last { " " } else { connector };
+                    out.push(format!("{}{}{}", rail, " ".repeat(indent - 1), line));
+                }
+            }
+
+            if !is_last {
+                out.push(connector.to_string());
+            }
+        }
+        out.join("\n")
+    }
+
+    /// Prints the timeline to the console.
+    pub fn print(&self) {
+        if !self.events.is_empty() {
+            println!("{}", self.render());
+        }
+    }
+}
+
+impl Render for Timeline {
+    fn render(&self) -> String {
+        Timeline::render(self)
+    }
+
+    fn print(&self) {
+        Timeline::print(self)
+    }
+}
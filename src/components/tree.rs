@@ -0,0 +1,181 @@
+use crate::components::Render;
+use crate::style::unicode_enabled;
+
+/// A labelled node in a `Tree`, with any number of child nodes.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::TreeNode;
+///
+/// let node = TreeNode::new("src")
+///     .child(TreeNode::new("lib.rs"))
+///     .child(TreeNode::new("main.rs"));
+/// ```
+pub struct TreeNode {
+    label: String,
+    children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    /// Creates a new `TreeNode` with the given label and no children.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The text displayed for this node.
+    ///
+    /// # Returns
+    ///
+    /// A new `TreeNode` instance.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds a child node below this node.
+    ///
+    /// This method consumes `self` and returns a new `TreeNode` instance,
+    /// allowing for method chaining. Children are drawn in the order they
+    /// are added.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The `TreeNode` to add as the last child.
+    ///
+    /// # Returns
+    ///
+    /// The `TreeNode` instance with the child added.
+    pub fn child(mut self, node: TreeNode) -> Self {
+        self.children.push(node);
+        self
+    }
+}
+
+impl Drop for TreeNode {
+    // Drop descendants iteratively so very deep trees can't overflow the stack
+    fn drop(&mut self) {
+        let mut pending = std::mem::take(&mut self.children);
+        while let Some(mut node) = pending.pop() {
+            pending.append(&mut node.children);
+        }
+    }
+}
+
+/// A component for displaying hierarchical data, such as file trees or
+/// dependency graphs.
+///
+/// The `Tree` struct draws its root label on the first line and each
+/// descendant below it, joined by `├──` and `└──` connectors, with `│`
+/// rails continuing alongside deeper levels. When Unicode is disabled, either
+/// with `ascii(true)` or globally with `set_unicode(false)`, the connectors
+/// become `|--` and `` `-- ``.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::{Tree, TreeNode};
+///
+/// let tree = Tree::new(
+///     TreeNode::new("cliux")
+///         .child(TreeNode::new("src").child(TreeNode::new("lib.rs")))
+///         .child(TreeNode::new("Cargo.toml")),
+/// )
+/// .render();
+///
+/// assert_eq!(
+///     tree,
+///     "cliux\n\
+///      ├── src\n\
+///      │   └── lib.rs\n\
+///      └── Cargo.toml"
+/// );
+/// ```
+pub struct Tree {
+    root: TreeNode,
+    ascii: bool,
+}
+
+impl Tree {
+    /// Creates a new `Tree` from its root node.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The `TreeNode` drawn at the top of the tree.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tree` instance.
+    pub fn new(root: TreeNode) -> Self {
+        Self { root, ascii: false }
+    }
+
+    /// Sets whether the connectors use ASCII characters only.
+    ///
+    /// This method consumes `self` and returns a new `Tree` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `ascii` - A boolean indicating whether to use ASCII-only connectors (`true`) or not (`false`).
+    ///
+    /// # Returns
+    ///
+    /// The `Tree` instance with the updated ASCII setting.
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Renders the tree into a `String`.
+    ///
+    /// The tree is walked iteratively, so even very deep trees can't
+    /// overflow the stack. Lines are separated by newlines, with no
+    /// trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered tree.
+    pub fn render(&self) -> String {
+        let (branch, last, rail, gap) = if self.ascii || !unicode_enabled() {
+            ("|-- ", "`-- ", "|   ", "    ")
+        } else {
+            ("├── ", "└── ", "│   ", "    ")
+        };
+
+        let mut lines = vec![self.root.label.clone()];
+        // Each entry holds a node, the prefix drawn before it, and whether it is the last sibling
+        let mut stack: Vec<(&TreeNode, String, bool)> = self
+            .root
+            .children
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, node)| (node, String::new(), i == self.root.children.len() - 1))
+            .collect();
+
+        while let Some((node, prefix, is_last)) = stack.pop() {
+            let connector = if is_last { last } else { branch };
+            lines.push(format!("{}{}{}", prefix, connector, node.label));
+
+            let child_prefix = format!("{}{}", prefix, if is_last { gap } else { rail });
+            for (i, child) in node.children.iter().enumerate().rev() {
+                let child_is_last = i == node.children.len() - 1;
+                stack.push((child, child_prefix.clone(), child_is_last));
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Prints the tree to the console.
+    pub fn print(&self) {
+        Render::print(self)
+    }
+}
+
+impl Render for Tree {
+    fn render(&self) -> String {
+        Tree::render(self)
+    }
+}
@@ -1,10 +1,16 @@
+use crate::components::Render;
+use crate::style::unicode_enabled;
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Pads the given `text` with spaces on the right to reach the specified `width`.
 ///
 /// This function is Unicode-aware, correctly handling multi-byte characters like emojis
-/// by considering their display width rather than byte length.
+/// by considering their display width rather than byte length. ANSI escape
+/// codes are not counted, so styled text such as `Tag::inline()` output pads
+/// to the same width as plain text. The escape codes are kept in the result.
 ///
 /// # Arguments
 ///
@@ -22,33 +28,391 @@ use unicode_width::UnicodeWidthStr;
 /// assert_eq!(pad("Hello", 10), "Hello     ");
 /// assert_eq!(pad("👋", 5), "👋   "); // Assuming emoji width 2
 /// assert_eq!(pad("Rust", 3), "Rust"); // No padding if width is less than or equal to text width
+///
+/// // A ZWJ family emoji is one two-column glyph
+/// assert_eq!(pad("👨\u{200d}👩\u{200d}👧", 10), format!("👨\u{200d}👩\u{200d}👧{}", " ".repeat(8)));
+///
+/// // Only the four visible columns of `[OK]` count towards the width
+/// assert_eq!(pad("\x1b[32m[OK]\x1b[0m", 10), format!("\x1b[32m[OK]\x1b[0m{}", " ".repeat(6)));
 /// ```
 pub fn pad(text: &str, width: usize) -> String {
-    let graphemes = UnicodeSegmentation::graphemes(text, true);
-    let mut display_width = 0;
+    let padding = width.saturating_sub(visible_width(text));
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+/// Horizontal alignment of text within a fixed width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Align {
+    /// Text starts at the left edge, padded on the right.
+    #[default]
+    Left,
+    /// Text is centered, with any odd column of padding on the right.
+    Center,
+    /// Text ends at the right edge, padded on the left.
+    Right,
+}
+
+/// Pads the given `text` with spaces on the left to reach the specified `width`.
+///
+/// This right-aligns the text, which is useful for numeric columns. Like
+/// `pad`, it measures display width, ignores ANSI escape codes, and never
+/// shortens `text`.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to pad.
+/// * `width` - The desired total width of the padded string.
+///
+/// # Returns
+///
+/// A `String` containing the original text padded to the specified width.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::pad_left;
+/// assert_eq!(pad_left("42", 5), "   42");
+/// ```
+pub fn pad_left(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(visible_width(text));
+    format!("{}{}", " ".repeat(padding), text)
+}
+
+/// Pads the given `text` with spaces on both sides to center it within `width`.
+///
+/// When the padding can't be split evenly, the extra space goes on the right.
+/// Like `pad`, it never shortens `text`. This is the same as `center`.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to pad.
+/// * `width` - The desired total width of the padded string.
+///
+/// # Returns
+///
+/// A `String` containing the original text centered within the specified width.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::pad_center;
+/// assert_eq!(pad_center("ab", 5), " ab  ");
+/// ```
+pub fn pad_center(text: &str, width: usize) -> String {
+    center(text, width)
+}
+
+/// Centers `text` within `width` columns, the primitive behind centered alignment.
+///
+/// Padding is split on both sides by display width, so emoji and CJK
+/// characters count as two columns, and ANSI escape codes are ignored so
+/// styled text centers correctly. When the padding can't be split evenly,
+/// the extra space goes on the right. Text wider than `width` is returned
+/// unchanged; pass it through `truncate` first to cut it to fit.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to center.
+/// * `width` - The desired total width of the centered string.
+///
+/// # Returns
+///
+/// A `String` containing the centered text.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::{center, truncate};
+///
+/// assert_eq!(center("abc", 7), "  abc  ");
+/// assert_eq!(center("日本", 7), " 日本  ");
+/// assert_eq!(center("a日b", 8), "  a日b  ");
+/// assert_eq!(center("too long", 4), "too long");
+/// assert_eq!(center(&truncate("too long", 4), 4), "too…");
+/// ```
+pub fn center(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(visible_width(text));
+    let left = padding / 2;
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(padding - left))
+}
 
-    for g in graphemes {
-        let w = g.width();
-        let adjusted = if is_emoji(g) && w == 1 { 2 } else { w };
-        display_width += adjusted;
+/// Pads the given `text` to `width` according to `align`.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to pad.
+/// * `width` - The desired total width of the padded string.
+/// * `align` - Where to place the text within the width.
+///
+/// # Returns
+///
+/// A `String` containing the aligned text.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::{Align, pad_align};
+/// assert_eq!(pad_align("ab", 4, Align::Right), "  ab");
+/// ```
+pub fn pad_align(text: &str, width: usize, align: Align) -> String {
+    match align {
+        Align::Left => pad(text, width),
+        Align::Center => pad_center(text, width),
+        Align::Right => pad_left(text, width),
     }
+}
 
-    let padding = width.saturating_sub(display_width);
-    format!("{}{}", text, " ".repeat(padding))
+/// Shortens `text` to at most `width` columns, marking the cut with `…`.
+///
+/// Text that already fits is returned unchanged. Otherwise it is cut at a
+/// grapheme boundary and the ellipsis (one column wide) is appended so the
+/// result is exactly `width` columns. When a wide character would straddle
+/// the cut, a space fills the gap it leaves.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to shorten.
+/// * `width` - The maximum display width of the result.
+///
+/// # Returns
+///
+/// A `String` no wider than `width`. A `width` of 1 yields `"…"` and a
+/// `width` of 0 yields an empty string.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::truncate;
+/// assert_eq!(truncate("Hello, world", 8), "Hello, …");
+/// assert_eq!(truncate("short", 8), "short");
+/// assert_eq!(truncate("日本語", 4), "日 …");
+/// ```
+pub fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let budget = width - 1;
+    let mut out = String::new();
+    let mut used = 0;
+    for g in UnicodeSegmentation::graphemes(text, true) {
+        let w = display_width(g);
+        if used + w > budget {
+            break;
+        }
+        out.push_str(g);
+        used += w;
+    }
+    out.push_str(&" ".repeat(budget - used));
+    out.push('…');
+    out
+}
+
+/// Fits `text` to exactly `width` columns.
+///
+/// Text that is too long is shortened with `truncate`, and text that is too
+/// short is padded on the right with `pad`.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to fit.
+/// * `width` - The exact display width of the result.
+///
+/// # Returns
+///
+/// A `String` exactly `width` columns wide.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::fit;
+/// assert_eq!(fit("Hello, world", 8), "Hello, …");
+/// assert_eq!(fit("Hi", 4), "Hi  ");
+/// ```
+pub fn fit(text: &str, width: usize) -> String {
+    pad(&truncate(text, width), width)
 }
 
-fn is_emoji(g: &str) -> bool {
-    g.chars().any(|c| {
+/// Measures the number of terminal columns `text` occupies.
+///
+/// Widths are summed per grapheme cluster, so combining marks add nothing
+/// and CJK characters count as two columns. An emoji cluster (see
+/// `is_emoji`) counts as exactly two columns, matching how most terminals
+/// draw it. This includes multi-code-point sequences such as flags, skin
+/// tones, and ZWJ families, whose parts would otherwise be counted
+/// separately. Symbols shown as text by default, such as `✓` and `❯`, are
+/// one column wide.
+///
+/// This is the measurement `pad`, `truncate`, and `wrap_text` use. ANSI
+/// escape sequences are not stripped and count towards the width.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to measure.
+///
+/// # Returns
+///
+/// The display width of `text` in terminal columns.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::display_width;
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("👋ab"), 4);
+/// assert_eq!(display_width("日本"), 4);
+///
+/// // A ZWJ family and a flag are each drawn as a single emoji
+/// assert_eq!(display_width("👨\u{200d}👩\u{200d}👧"), 2);
+/// assert_eq!(display_width("🇳🇱"), 2);
+///
+/// // Text-style symbols are narrow unless followed by U+FE0F
+/// assert_eq!(display_width("✓"), 1);
+/// assert_eq!(display_width("❤\u{fe0f}"), 2);
+/// ```
+pub fn display_width(text: &str) -> usize {
+    UnicodeSegmentation::graphemes(text, true)
+        .map(|g| if is_emoji(g) { 2 } else { g.width() })
+        .sum()
+}
+
+/// Measures the visible width of `text`, ignoring any ANSI escape sequences it contains.
+///
+/// This is `display_width` applied after `strip_ansi`, so it gives the right
+/// width for the styled strings returned by `Label::inline` and `Tag::inline`.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to measure, which may contain escape sequences.
+///
+/// # Returns
+///
+/// The number of terminal columns the visible text occupies.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::visible_width;
+/// assert_eq!(visible_width("\x1b[1;32m[OK]\x1b[0m"), 4);
+/// ```
+pub fn visible_width(text: &str) -> usize {
+    display_width(&strip_ansi(text))
+}
+
+/// Removes ANSI escape sequences so only the visible characters remain.
+///
+/// CSI sequences, such as SGR color and style codes, are removed, as are
+/// OSC sequences such as OSC 8 hyperlinks, whose link text is kept.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to strip.
+///
+/// # Returns
+///
+/// A `String` containing only the visible characters of `text`.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::strip_ansi;
+///
+/// // Nested color and bold sequences
+/// assert_eq!(strip_ansi("\x1b[31m\x1b[1mError:\x1b[0m\x1b[0m done"), "Error: done");
+///
+/// // An OSC 8 hyperlink, terminated by ESC \
+/// assert_eq!(
+///     strip_ansi("\x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\"),
+///     "docs"
+/// );
+/// ```
+pub fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' && chars.clone().next() == Some(']') {
+            // Skip an OSC sequence (e.g. a hyperlink) up to its BEL or ESC \ terminator
+            while let Some(next) = chars.next() {
+                if next == '\x07' || (next == '\x1b' && chars.next() == Some('\\')) {
+                    break;
+                }
+            }
+        } else if c == '\x1b' {
+            // Skip up to and including the final byte of a CSI sequence
+            for next in chars.by_ref() {
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Checks whether a grapheme cluster is drawn as an emoji.
+///
+/// Used by `display_width` to measure every emoji cluster as two columns.
+/// A cluster counts as an emoji if it contains the variation selector
+/// U+FE0F, which requests emoji presentation, if it is a flag made of two
+/// regional indicators, if it joins pictographs with a zero-width joiner,
+/// or if it starts with a symbol shown as an emoji by default (Unicode's
+/// `Emoji_Presentation`). Symbols shown as text by default, such as `✓`,
+/// `❯`, and `☐`, are not emoji unless followed by U+FE0F.
+///
+/// # Arguments
+///
+/// * `g` - A single grapheme cluster, as produced by splitting text on grapheme boundaries.
+///
+/// # Returns
+///
+/// `true` if `g` is drawn as an emoji.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::is_emoji;
+/// assert!(is_emoji("👋"));
+/// assert!(is_emoji("✅"));
+/// assert!(is_emoji("❤\u{fe0f}"));
+/// assert!(is_emoji("🇳🇱"));
+/// assert!(!is_emoji("☀"));
+/// assert!(!is_emoji("✓"));
+/// assert!(!is_emoji("a"));
+/// ```
+pub fn is_emoji(g: &str) -> bool {
+    let pictographic = |c: char| {
         let code = c as u32;
-        (code >= 0x1F300 && code <= 0x1FAFF) || (code >= 0x2600 && code <= 0x26FF)
-    })
+        (0x1F000..=0x1FAFF).contains(&code) || (0x2300..=0x2BFF).contains(&code)
+    };
+    let Some(first) = g.chars().next() else {
+        return false;
+    };
+    if g.contains('\u{FE0F}') {
+        return true;
+    }
+    if ('\u{1F1E6}'..='\u{1F1FF}').contains(&first) {
+        return g.chars().count() >= 2;
+    }
+    if g.contains('\u{200D}') && g.chars().any(pictographic) {
+        return true;
+    }
+    // Unicode makes every Emoji_Presentation symbol wide, while text-style symbols stay narrow
+    pictographic(first) && first.width() == Some(2)
 }
 
 /// Wraps the given `text` into a vector of strings, ensuring that each line
 /// does not exceed the specified `width`.
 ///
 /// The wrapping is done intelligently, breaking at word boundaries.
-/// Paragraphs are maintained by processing `text` line by line.
+/// Paragraphs are maintained by processing `text` line by line. Widths are
+/// measured in terminal columns, so accented, CJK, and emoji text wraps
+/// according to how wide it appears rather than its byte length.
 ///
 /// # Arguments
 ///
@@ -58,6 +422,7 @@ fn is_emoji(g: &str) -> bool {
 /// # Returns
 ///
 /// A `Vec<String>` where each element is a wrapped line of the original text.
+/// Blank lines in `text` are returned as empty strings.
 ///
 /// # Examples
 ///
@@ -66,35 +431,697 @@ fn is_emoji(g: &str) -> bool {
 /// let long_text = "This is a very long sentence that needs to be wrapped.";
 /// let wrapped_lines = wrap_text(long_text, 20);
 /// assert_eq!(wrapped_lines, vec![
-///     "This is a very",
-///     "long sentence that",
-///     "needs to be wrapped."
+///     "This is a very long",
+///     "sentence that needs",
+///     "to be wrapped."
 /// ]);
 ///
 /// let paragraph_text = "First paragraph.\nSecond paragraph is a bit longer and needs wrapping.";
 /// let wrapped_paragraphs = wrap_text(paragraph_text, 25);
 /// assert_eq!(wrapped_paragraphs, vec![
 ///     "First paragraph.",
-///     "Second paragraph is a",
-///     "bit longer and needs",
+///     "Second paragraph is a bit",
+///     "longer and needs",
 ///     "wrapping."
 /// ]);
+///
+/// let mixed = wrap_text("Rust 是一种 系统编程语言 that is fast 而且 安全", 20);
+/// assert_eq!(mixed, vec!["Rust 是一种", "系统编程语言 that is", "fast 而且 安全"]);
+///
+/// // Blank lines between paragraphs are kept
+/// assert_eq!(wrap_text("a\n\nb", 40), vec!["a", "", "b"]);
+///
+/// // A line may fill the width exactly
+/// assert_eq!(wrap_text("ab cd", 5), vec!["ab cd"]);
+/// assert_eq!(wrap_text("ab cd", 4), vec!["ab", "cd"]);
 /// ```
 pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    wrap_text_with_spans(text, width)
+        .into_iter()
+        .map(|(line, _)| line)
+        .collect()
+}
+
+/// Wraps `text` while keeping its indentation and runs of spaces.
+///
+/// `wrap_text` rebuilds each line from its words, which suits prose but
+/// loses the layout of code snippets and pre-formatted output. This keeps
+/// every line as written: lines that fit are returned unchanged, and longer
+/// lines are broken at the last space before `width`. Continuation lines
+/// repeat the leading whitespace of the line they came from, so indented
+/// list items stay aligned. Spaces at a break are dropped, and a word with
+/// no space before the limit is left whole rather than split.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to wrap.
+/// * `width` - The maximum desired width for each wrapped line.
+///
+/// # Returns
+///
+/// A `Vec<String>` with one or more lines per line of `text`.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::wrap_text_preserve;
+///
+/// let code = "fn main() {\n    println!(\"hi\");\n}";
+/// assert_eq!(wrap_text_preserve(code, 40), vec!["fn main() {", "    println!(\"hi\");", "}"]);
+///
+/// let list = "Steps:\n  - install the   toolchain first";
+/// assert_eq!(
+///     wrap_text_preserve(list, 20),
+///     vec!["Steps:", "  - install the", "  toolchain first"]
+/// );
+/// ```
+pub fn wrap_text_preserve(text: &str, width: usize) -> Vec<String> {
     let mut lines = Vec::new();
-    for paragraph in text.lines() {
+    for raw in text.lines() {
+        let body = raw.trim_start_matches([' ', '\t']);
+        let lead = &raw[..raw.len() - body.len()];
+        let room = width.saturating_sub(display_width(lead)).max(1);
+
+        let mut rest = body;
+        loop {
+            if display_width(rest) <= room {
+                lines.push(format!("{}{}", lead, rest));
+                break;
+            }
+            // Break at the last space that keeps the line within the width,
+            // or after the first word when it alone is too wide
+            let mut used = 0;
+            let mut cut = None;
+            for (i, g) in rest.grapheme_indices(true) {
+                if g == " " && i > 0 {
+                    if used > room && cut.is_some() {
+                        break;
+                    }
+                    cut = Some(i);
+                    if used > room {
+                        break;
+                    }
+                }
+                used += display_width(g);
+            }
+            let Some(cut) = cut else {
+                lines.push(format!("{}{}", lead, rest));
+                break;
+            };
+            lines.push(format!("{}{}", lead, rest[..cut].trim_end_matches(' ')));
+            rest = rest[cut..].trim_start_matches(' ');
+            if rest.is_empty() {
+                break;
+            }
+        }
+    }
+    lines
+}
+
+/// Wraps `text` like `wrap_text`, also returning where each line came from.
+///
+/// Each wrapped line is paired with the byte range it covers in the original
+/// `text`, from the start of its first word to the end of its last. This lets
+/// callers map offsets found in the original text (such as search matches)
+/// onto the wrapped lines, even when a match spans a line break.
+///
+/// # Arguments
+///
+/// * `text` - The string slice to wrap.
+/// * `width` - The maximum desired width for each wrapped line.
+///
+/// # Returns
+///
+/// A `Vec` of `(line, range)` pairs, one per wrapped line.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::wrap_text_with_spans;
+///
+/// let text = "find the needle  in here";
+/// let lines = wrap_text_with_spans(text, 12);
+/// assert_eq!(lines[0], ("find the".to_string(), 0..8));
+/// assert_eq!(lines[1], ("needle in".to_string(), 9..19));
+/// assert_eq!(&text[lines[1].1.clone()], "needle  in");
+/// ```
+pub fn wrap_text_with_spans(text: &str, width: usize) -> Vec<(String, Range<usize>)> {
+    let mut lines = Vec::new();
+    let mut offset = 0;
+
+    for raw in text.split_inclusive('\n') {
+        let paragraph = raw.trim_end_matches(['\n', '\r']);
         let mut current = String::new();
-        for word in paragraph.split_whitespace() {
-            if current.len() + word.len() + 1 > width {
-                lines.push(current.trim_end().to_string());
-                current.clear();
+        let mut current_width = 0;
+        let mut range = 0..0;
+
+        for (start, word) in words_with_offsets(paragraph) {
+            let (start, end) = (offset + start, offset + start + word.len());
+            let word_width = display_width(word);
+            if !current.is_empty() && current_width + 1 + word_width > width {
+                lines.push((std::mem::take(&mut current), range.clone()));
+                current_width = 0;
+            }
+            if current.is_empty() {
+                range.start = start;
+            } else {
+                current.push(' ');
+                current_width += 1;
             }
             current.push_str(word);
-            current.push(' ');
+            current_width += word_width;
+            range.end = end;
+        }
+        // A line without words is kept as a blank line, so paragraphs stay apart
+        if current.is_empty() {
+            range = offset..offset;
+        }
+        lines.push((current, range));
+
+        offset += raw.len();
+    }
+    lines
+}
+
+/// Wraps `text` like `wrap_text`, then breaks any line that is still too wide.
+///
+/// Words longer than `width` are split between graphemes so that every
+/// returned line fits, which keeps bordered layouts aligned.
+pub(crate) fn hard_wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for line in wrap_text(text, width) {
+        if display_width(&line) <= width {
+            lines.push(line);
+            continue;
+        }
+        let mut current = String::new();
+        for g in UnicodeSegmentation::graphemes(line.as_str(), true) {
+            if !current.is_empty() && display_width(&current) + display_width(g) > width {
+                lines.push(std::mem::take(&mut current));
+            }
+            current.push_str(g);
+        }
+        lines.push(current);
+    }
+    lines
+}
+
+/// Splits `text` into lines indented by `indent` spaces, wrapping them if `wrap` is set.
+///
+/// Lines after the first of each wrapped paragraph get `hanging` more spaces.
+/// Both indents are clamped so they never exceed `width`, and wrapping leaves
+/// room for them so every line fits. Blank lines stay empty.
+pub(crate) fn indent_lines(
+    text: &str,
+    width: usize,
+    wrap: bool,
+    indent: usize,
+    hanging: usize,
+) -> Vec<String> {
+    let indent = indent.min(width);
+    let hanging = hanging.min(width - indent);
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let wrapped = if wrap {
+            wrap_text(paragraph, (width - indent - hanging).max(1))
+        } else {
+            vec![paragraph.to_string()]
+        };
+        for (i, line) in wrapped.into_iter().enumerate() {
+            let lead = if i == 0 { indent } else { indent + hanging };
+            if line.is_empty() {
+                lines.push(line);
+            } else {
+                lines.push(format!("{}{}", " ".repeat(lead), line));
+            }
         }
-        if !current.is_empty() {
-            lines.push(current.trim_end().to_string());
+        if paragraph.is_empty() && wrap {
+            lines.push(String::new());
         }
     }
     lines
 }
+
+/// Splits `text` on whitespace, yielding each word with its byte offset.
+fn words_with_offsets(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split(char::is_whitespace)
+        .filter(|word| !word.is_empty())
+        .map(move |word| (word.as_ptr() as usize - text.as_ptr() as usize, word))
+}
+
+/// Returns the width of the terminal attached to standard output, in columns.
+///
+/// # Returns
+///
+/// `Some(width)` when standard output is a terminal whose size can be
+/// queried, otherwise `None` (e.g. when output is piped to a file).
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::terminal_width;
+///
+/// let width = terminal_width().unwrap_or(80);
+/// assert!(width > 0);
+/// ```
+pub fn terminal_width() -> Option<usize> {
+    terminal_size::terminal_size().map(|(terminal_size::Width(w), _)| w as usize)
+}
+
+static DEFAULT_WIDTH: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets the width that newly created components use when none is given.
+///
+/// Without a default, `Note`, `Boxed`, `Section`, and `Alert` are 50 columns
+/// wide and the `Input`, `Confirm`, `Select`, and `MultiSelect` prompts are
+/// 40. Once set, they all take up `width` columns on screen instead, borders
+/// included, and `full_width()` falls back to it when the terminal size
+/// can't be detected. A `.width()` call
+/// on a single component still overrides it. Passing `0` restores the
+/// built-in defaults.
+///
+/// # Arguments
+///
+/// * `width` - The default width in columns, or `0` for the built-in defaults.
+///
+/// # Examples
+///
+/// ```standalone_crate
+/// use cliux::{Boxed, Note, set_default_width};
+///
+/// set_default_width(12);
+/// assert_eq!(Note::new("x").style("+").render(), "+----------+\n| x        |\n+----------+");
+/// assert_eq!(
+///     Boxed::new("T").content("x").render(),
+///     "╭──────────╮\n│ T        │\n├──────────┤\n│ x        │\n╰──────────╯"
+/// );
+/// assert_eq!(Note::new("x").style("+").width(5).render(), "+---+\n| x |\n+---+");
+/// ```
+pub fn set_default_width(width: usize) {
+    DEFAULT_WIDTH.store(width, Ordering::Relaxed);
+}
+
+/// Returns the width set by `set_default_width`, or `None` if none is set.
+pub fn default_width() -> Option<usize> {
+    Some(DEFAULT_WIDTH.load(Ordering::Relaxed)).filter(|&w| w > 0)
+}
+
+/// Arranges equal-width cells into rows that fit within a terminal width.
+///
+/// Each cell is padded to `cell_width` columns and as many cells as fit are
+/// placed on each row before wrapping to the next. Widths are measured on the
+/// visible text, so cells containing emoji or ANSI colors still line up.
+///
+/// # Arguments
+///
+/// * `cells` - The pre-rendered cells to arrange.
+/// * `cell_width` - The width each cell is padded to, in columns.
+/// * `term_width` - The available width, or `None` to assume 80 columns.
+///
+/// # Returns
+///
+/// A `String` containing the rows of the grid separated by newlines.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::grid;
+///
+/// let cells: Vec<String> = ["red", "green", "blue", "cyan", "white"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// assert_eq!(
+///     grid(&cells, 7, Some(21)),
+///     "red    green  blue   \ncyan   white  "
+/// );
+/// ```
+pub fn grid(cells: &[String], cell_width: usize, term_width: Option<usize>) -> String {
+    let per_row = (term_width.unwrap_or(80) / cell_width.max(1)).max(1);
+
+    cells
+        .chunks(per_row)
+        .map(|row| {
+            row.iter()
+                .map(|cell| {
+                    let padding = cell_width.saturating_sub(visible_width(cell));
+                    format!("{}{}", cell, " ".repeat(padding))
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Places pre-rendered blocks side by side.
+///
+/// Each block is split into lines and every line is padded to the width of
+/// the block's widest line, so each block keeps its own column. Lines at the
+/// same height are then joined with `gap` spaces between blocks. Shorter
+/// blocks are filled out with blank lines to the height of the tallest.
+/// Widths are measured on the visible text, so blocks containing emoji or
+/// ANSI colors still line up.
+///
+/// # Arguments
+///
+/// * `blocks` - The rendered blocks to place, from left to right.
+/// * `gap` - The number of spaces between neighbouring blocks.
+///
+/// # Returns
+///
+/// A `String` containing the joined rows separated by newlines.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::Boxed;
+/// use cliux::layout::columns;
+///
+/// let left = Boxed::new("CPU").content("42%").width(8).render();
+/// let right = Boxed::new("Memory").content("1.2 GB").width(10).render();
+/// println!("{}", columns(&[left, right], 3));
+///
+/// assert_eq!(
+///     columns(&["a\nbb".to_string(), "ccc".to_string()], 1),
+///     "a  ccc\nbb    "
+/// );
+/// ```
+pub fn columns(blocks: &[String], gap: usize) -> String {
+    let blocks: Vec<Vec<&str>> = blocks.iter().map(|b| b.lines().collect()).collect();
+    let widths: Vec<usize> = blocks
+        .iter()
+        .map(|lines| lines.iter().map(|l| visible_width(l)).max().unwrap_or(0))
+        .collect();
+    let height = blocks.iter().map(|lines| lines.len()).max().unwrap_or(0);
+    let spacer = " ".repeat(gap);
+
+    (0..height)
+        .map(|row| {
+            blocks
+                .iter()
+                .zip(&widths)
+                .map(|(lines, &width)| {
+                    let line = lines.get(row).copied().unwrap_or("");
+                    let padding = width.saturating_sub(visible_width(line));
+                    format!("{}{}", line, " ".repeat(padding))
+                })
+                .collect::<Vec<_>>()
+                .join(&spacer)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Arranges pre-rendered blocks into rows of `cols` blocks each.
+///
+/// Blocks are placed left to right, top to bottom. Each row is joined with
+/// `columns`, so blocks in a row are separated by `gap` spaces and shorter
+/// blocks are filled out to the tallest block in that row. Rows are stacked
+/// with `gap` blank lines between them. A final row with fewer than `cols`
+/// blocks holds only those blocks, with no empty cells after them.
+///
+/// Unlike `grid`, which flows equal-width single-line cells to fit the
+/// terminal, this function lays out multi-line blocks such as rendered
+/// `Boxed` or `Note` cards.
+///
+/// # Arguments
+///
+/// * `blocks` - The rendered blocks to arrange.
+/// * `cols` - The number of blocks per row. A value of 0 is treated as 1.
+/// * `gap` - The number of spaces between blocks and blank lines between rows.
+///
+/// # Returns
+///
+/// A `String` containing the arranged blocks separated by newlines.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::block_grid;
+///
+/// let cards: Vec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+/// assert_eq!(block_grid(&cards, 2, 1), "a b\n\nc");
+/// ```
+pub fn block_grid(blocks: &[String], cols: usize, gap: usize) -> String {
+    let separator = "\n".repeat(gap + 1);
+    blocks
+        .chunks(cols.max(1))
+        .map(|row| columns(row, gap))
+        .collect::<Vec<_>>()
+        .join(&separator)
+}
+
+/// Block characters used by `sparkline`, from lowest to highest.
+const SPARK_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Renders a series of values as a compact sparkline of block characters.
+///
+/// Each value is normalized between the series minimum and maximum and
+/// mapped to one of eight block heights (`▁▂▃▄▅▆▇█`). Every block is a single
+/// column wide, so the result is as wide as the number of values drawn.
+/// Series where every value is equal render as a flat mid-level line, and
+/// non-finite values (NaN or infinity) are skipped.
+///
+/// # Arguments
+///
+/// * `values` - The series of values to draw.
+///
+/// # Returns
+///
+/// A `String` containing one block character per finite value.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::sparkline;
+///
+/// assert_eq!(sparkline(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]), "▁▂▃▄▅▆▇█");
+/// assert_eq!(sparkline(&[2.0, 2.0, 2.0]), "▄▄▄");
+/// assert_eq!(sparkline(&[]), "");
+/// ```
+pub fn sparkline(values: &[f64]) -> String {
+    let finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    let min = finite.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = finite.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    let top = (SPARK_BLOCKS.len() - 1) as f64;
+
+    finite
+        .iter()
+        .map(|v| {
+            if range == 0.0 {
+                SPARK_BLOCKS[3]
+            } else {
+                SPARK_BLOCKS[((v - min) / range * top).round() as usize]
+            }
+        })
+        .collect()
+}
+
+/// Eighth-block characters used by `smooth_bar`, from one eighth to seven eighths.
+const BAR_EIGHTHS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// Renders a fraction as a bar of filled and empty cells, such as `███░░░`.
+///
+/// Unlike `ProgressBar`, this only builds a string, so the bar can be placed
+/// in a `Table` cell or a `KeyValue` value. Fractions are clamped to `0.0`
+/// to `1.0`, with NaN treated as `0.0`, and only whole cells are filled, so
+/// the bar is full only at `1.0`. When Unicode is disabled with
+/// `set_unicode(false)`, the bar is drawn with `#` and `-` instead. The
+/// result is always exactly `width` columns wide.
+///
+/// # Arguments
+///
+/// * `fraction` - How much of the bar to fill, from `0.0` to `1.0`.
+/// * `width` - The width of the bar in columns.
+///
+/// # Returns
+///
+/// A `String` containing the bar.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::{bar, display_width};
+///
+/// assert_eq!(bar(0.0, 6), "░░░░░░");
+/// assert_eq!(bar(1.0, 6), "██████");
+/// assert_eq!(bar(0.5, 6), "███░░░");
+/// assert_eq!(bar(1.5, 4), "████");
+/// assert_eq!(display_width(&bar(0.37, 10)), 10);
+/// ```
+pub fn bar(fraction: f64, width: usize) -> String {
+    let (fill, empty) = if unicode_enabled() {
+        ("█", "░")
+    } else {
+        ("#", "-")
+    };
+    let filled = (clamp_fraction(fraction) * width as f64).floor() as usize;
+    format!("{}{}", fill.repeat(filled), empty.repeat(width - filled))
+}
+
+/// Renders a fraction as a bar with eighth-cell precision, such as `██▌░░`.
+///
+/// This works like `bar`, but the cell where the fill ends is drawn with
+/// one of the partial blocks `▏▎▍▌▋▊▉`, so small changes stay visible in
+/// narrow bars. When Unicode is disabled, it falls back to `bar`. The
+/// result is always exactly `width` columns wide.
+///
+/// # Arguments
+///
+/// * `fraction` - How much of the bar to fill, from `0.0` to `1.0`.
+/// * `width` - The width of the bar in columns.
+///
+/// # Returns
+///
+/// A `String` containing the bar.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::layout::{display_width, smooth_bar};
+///
+/// assert_eq!(smooth_bar(0.0, 5), "░░░░░");
+/// assert_eq!(smooth_bar(1.0, 5), "█████");
+/// assert_eq!(smooth_bar(0.5, 5), "██▌░░");
+/// assert_eq!(display_width(&smooth_bar(0.37, 10)), 10);
+/// ```
+pub fn smooth_bar(fraction: f64, width: usize) -> String {
+    if !unicode_enabled() {
+        return bar(fraction, width);
+    }
+    let eighths = (clamp_fraction(fraction) * width as f64 * 8.0).floor() as usize;
+    let (filled, rest) = (eighths / 8, eighths % 8);
+    let partial = match rest {
+        0 => String::new(),
+        n => BAR_EIGHTHS[n - 1].to_string(),
+    };
+    let empty = width - filled - usize::from(rest > 0);
+    format!("{}{}{}", "█".repeat(filled), partial, "░".repeat(empty))
+}
+
+/// Clamps `fraction` to `0.0..=1.0`, treating NaN as `0.0`.
+fn clamp_fraction(fraction: f64) -> f64 {
+    if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, 1.0)
+    }
+}
+
+/// A sequence of components rendered one after another with even spacing.
+///
+/// Printing components one by one leaves the spacing between them up to
+/// each call site. A `Document` collects the components first and joins
+/// their output with the same number of blank lines between each, so the
+/// whole page can be printed at once or captured as a `String`.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::{Divider, Document, List};
+///
+/// let page = Document::new()
+///     .add(Divider::new(10).style('='))
+///     .add(List::new(vec!["Build", "Test"]))
+///     .spacing(1)
+///     .render();
+///
+/// assert_eq!(page, "==========\n\n• Build\n• Test");
+/// ```
+pub struct Document {
+    blocks: Vec<Box<dyn Render>>,
+    spacing: usize,
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Document {
+    /// Creates a new, empty `Document`.
+    ///
+    /// By default, components are separated by one blank line.
+    ///
+    /// # Returns
+    ///
+    /// A new `Document` instance.
+    pub fn new() -> Self {
+        Self {
+            blocks: Vec::new(),
+            spacing: 1,
+        }
+    }
+
+    /// Appends a component to the document.
+    ///
+    /// This method consumes `self` and returns a new `Document` instance,
+    /// allowing for method chaining. Components are rendered in the order
+    /// they are added.
+    ///
+    /// # Arguments
+    ///
+    /// * `component` - Any component that implements `Render`.
+    ///
+    /// # Returns
+    ///
+    /// The `Document` instance with the component added.
+    #[allow(clippy::should_implement_trait)] // Appends to the document; not `+`
+    pub fn add(mut self, component: impl Render + 'static) -> Self {
+        self.blocks.push(Box::new(component));
+        self
+    }
+
+    /// Sets the number of blank lines between components.
+    ///
+    /// This method consumes `self` and returns a new `Document` instance,
+    /// allowing for method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The number of blank lines to insert between components.
+    ///
+    /// # Returns
+    ///
+    /// The `Document` instance with the updated spacing.
+    pub fn spacing(mut self, lines: usize) -> Self {
+        self.spacing = lines;
+        self
+    }
+
+    /// Renders every component into a single `String`.
+    ///
+    /// Lines are separated by newlines, with no trailing newline.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered document.
+    pub fn render(&self) -> String {
+        let separator = "\n".repeat(self.spacing + 1);
+        self.blocks
+            .iter()
+            .map(|block| block.render())
+            .collect::<Vec<_>>()
+            .join(&separator)
+    }
+
+    /// Prints the whole document to the console.
+    pub fn print(&self) {
+        if !self.blocks.is_empty() {
+            println!("{}", self.render());
+        }
+    }
+}
+
+impl Render for Document {
+    fn render(&self) -> String {
+        Document::render(self)
+    }
+
+    fn print(&self) {
+        Document::print(self)
+    }
+}
@@ -5,7 +5,16 @@
 
 pub mod components;
 pub mod layout;
+pub mod style;
+pub mod term;
+pub mod theme;
 
+/// Re-exports the `Alert` struct from the `components` module.
+pub use components::Alert;
+/// Re-exports the `Badge` struct from the `components` module.
+pub use components::Badge;
+/// Re-exports the `Banner` struct from the `components` module.
+pub use components::Banner;
 /// Re-exports the `Boxed` struct from the `components` module.
 pub use components::Boxed;
 /// Re-exports the `Confirm` struct from `components` module.
@@ -14,15 +23,56 @@ pub use components::Confirm;
 pub use components::Divider;
 /// Re-exports the `Input` struct from `components` module.
 pub use components::Input;
+/// Re-exports the `KeyValue` struct from the `components` module.
+pub use components::KeyValue;
 /// Re-exports the `Label` struct from the `components` module.
 pub use components::Label;
 /// Re-exports the `List` struct from the `components` module.
 pub use components::List;
+/// Re-exports the `MultiSelect` struct from the `components` module.
+pub use components::MultiSelect;
 /// Re-exports the `Note` struct from the `components` module.
 pub use components::Note;
+/// Re-exports the `ProgressBar` struct from the `components` module.
+pub use components::ProgressBar;
+/// Re-exports the `Render` trait from the `components` module.
+pub use components::Render;
 /// Re-exports the `Section` struct from the `components` module.
 pub use components::Section;
+/// Re-exports the `Select` struct from the `components` module.
+pub use components::Select;
+/// Re-exports the `Sparkline` struct from the `components` module.
+pub use components::Sparkline;
 /// Re-exports the `Table` struct from the `components` module.
 pub use components::Table;
+/// Re-exports the `TableRow` trait from the `components` module.
+pub use components::TableRow;
 /// Re-exports the `Tag` struct from the `components` module.
 pub use components::Tag;
+/// Re-exports the `Timeline` struct from the `components` module.
+pub use components::Timeline;
+/// Re-exports the `NumberFormat` enum from the `components` module.
+pub use components::list::NumberFormat;
+/// Re-exports the `NoteLevel` enum, `NoteKind` registry, and threshold functions from the `note` module.
+pub use components::note::{
+    NoteKind, NoteLevel, min_note_level, register_note_kind, set_min_note_level,
+};
+/// Re-exports the `Span` enum used for rich `Section` content.
+pub use components::section::Span;
+/// Re-exports the `AsyncSpinner` and `SpinnerGuard` structs from the `components` module.
+#[cfg(feature = "tokio")]
+pub use components::{AsyncSpinner, SpinnerGuard};
+/// Re-exports the `Tree` and `TreeNode` structs from the `components` module.
+pub use components::{Tree, TreeNode};
+/// Re-exports the `Align` enum from the `layout` module.
+pub use layout::Align;
+/// Re-exports the `Document` struct from the `layout` module.
+pub use layout::Document;
+/// Re-exports the global default width setter and getter from the `layout` module.
+pub use layout::{default_width, set_default_width};
+/// Re-exports the color and Unicode controls and `reset` function from the `style` module.
+pub use style::{
+    ColorMode, color_enabled, color_mode, reset, set_color_mode, set_unicode, unicode_enabled,
+};
+/// Re-exports the `Theme` struct and its global setters from the `theme` module.
+pub use theme::{Theme, set_theme, theme};
@@ -0,0 +1,150 @@
+//! Shared helpers for applying ANSI styles to component output.
+
+use ansi_term::Style;
+use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+/// Controls whether components emit ANSI styling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Style output only when standard output is a terminal and `NO_COLOR` is not set.
+    Auto,
+    /// Always style output, even when piped.
+    Always,
+    /// Never style output.
+    Never,
+}
+
+static COLOR_MODE: AtomicU8 = AtomicU8::new(ColorMode::Auto as u8);
+static UNICODE: AtomicBool = AtomicBool::new(true);
+
+/// Sets whether components emit ANSI colors and text styles.
+///
+/// The default, `ColorMode::Auto`, disables styling when the `NO_COLOR`
+/// environment variable is set to a non-empty value (see
+/// <https://no-color.org>) or when standard output is not a terminal, so
+/// piped output and logs stay free of escape codes. Use `Always` or `Never`
+/// to override the detection, e.g. from a `--color` flag.
+///
+/// # Arguments
+///
+/// * `mode` - The `ColorMode` to apply to every subsequently rendered component.
+///
+/// # Examples
+///
+/// ```
+/// use cliux::{ColorMode, Tag, set_color_mode};
+///
+/// set_color_mode(ColorMode::Never);
+/// assert_eq!(Tag::new("OK").color("green").inline(), "[OK]");
+/// set_color_mode(ColorMode::Auto);
+/// ```
+pub fn set_color_mode(mode: ColorMode) {
+    COLOR_MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+/// Returns the current `ColorMode`.
+pub fn color_mode() -> ColorMode {
+    match COLOR_MODE.load(Ordering::Relaxed) {
+        1 => ColorMode::Always,
+        2 => ColorMode::Never,
+        _ => ColorMode::Auto,
+    }
+}
+
+/// Returns whether ANSI styling should be emitted under the current `ColorMode`.
+pub fn color_enabled() -> bool {
+    match color_mode() {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => {
+            let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+            !no_color && stdout_is_terminal()
+        }
+    }
+}
+
+/// Sets whether components may draw Unicode glyphs.
+///
+/// When disabled, every component falls back to pure ASCII at render time:
+/// borders in `Note`, `Boxed`, `Alert`, and `Table` use `+`, `-`, and `|`,
+/// `Section` and `Divider` rules use `-`, `Timeline` nodes become `*` and
+/// `o`, the default `List` bullet becomes `*`, and `Note` kind icons are
+/// replaced with markers such as `[i]` and `[!]`. Glyphs you set explicitly,
+/// like a custom bullet or icon, are left as they are. This is useful for CI
+/// logs and consoles that can't display box-drawing characters.
+///
+/// # Arguments
+///
+/// * `enabled` - `true` to allow Unicode glyphs (the default), `false` for ASCII only.
+///
+/// # Examples
+///
+/// ```standalone_crate
+/// use cliux::{Note, set_unicode};
+///
+/// set_unicode(false);
+/// let note = Note::new("Saved").style("rounded").width(12).render();
+/// set_unicode(true);
+///
+/// assert_eq!(note, "+----------+\n| Saved    |\n+----------+");
+/// ```
+pub fn set_unicode(enabled: bool) {
+    UNICODE.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether components may draw Unicode glyphs, as set by `set_unicode`.
+pub fn unicode_enabled() -> bool {
+    UNICODE.load(Ordering::Relaxed)
+}
+
+/// Returns whether standard output is attached to an interactive terminal.
+pub(crate) fn stdout_is_terminal() -> bool {
+    io::stdout().is_terminal()
+}
+
+/// Paints `text` with `style`, terminating the style at the end of every line.
+///
+/// `ansi_term` only emits a reset after the whole painted string, so text
+/// containing newlines would otherwise carry its color across line
+/// boundaries. Painting each line separately keeps every line self-contained.
+/// When color is disabled by the `ColorMode`, `text` is returned unstyled.
+pub(crate) fn paint(style: &Style, text: &str) -> String {
+    if !color_enabled() {
+        return text.to_string();
+    }
+    text.split('\n')
+        .map(|line| style.paint(line).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Wraps `text` in an OSC 8 hyperlink to `url`.
+///
+/// Terminals that support OSC 8 show `text` as a clickable link, while others
+/// ignore the sequence and show the text alone. Like other escape codes, the
+/// hyperlink is omitted when color is disabled by the `ColorMode`.
+pub(crate) fn hyperlink(url: &str, text: &str) -> String {
+    if !color_enabled() {
+        return text.to_string();
+    }
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+/// Prints the ANSI reset sequence (`\x1b[0m`) to standard output.
+///
+/// Every component already resets its styles at the end of each line, but
+/// a program interrupted mid-write can leave the terminal colored. Calling
+/// `reset` from a cleanup path (e.g. a panic hook or signal handler) restores
+/// the terminal's default style.
+///
+/// # Examples
+///
+/// ```
+/// cliux::reset();
+/// ```
+pub fn reset() {
+    print!("\x1b[0m");
+    let _ = io::stdout().flush();
+}
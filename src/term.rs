@@ -0,0 +1,110 @@
+//! Cursor movement and screen clearing for redrawing output in place.
+//!
+//! Each function writes an ANSI escape sequence to standard output and
+//! flushes it. When standard output is not a terminal, such as when it is
+//! piped to a file, they write nothing, so logs stay free of escape codes.
+
+use crate::style::stdout_is_terminal;
+use std::io::{self, Write};
+
+/// Writes `sequence` to standard output if it is a terminal.
+fn emit(sequence: &str) {
+    if stdout_is_terminal() {
+        let mut stdout = io::stdout();
+        let _ = stdout.write_all(sequence.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
+/// Clears the current line and moves the cursor to its start.
+///
+/// # Examples
+///
+/// ```no_run
+/// use cliux::term::clear_line;
+///
+/// print!("Working...");
+/// clear_line();
+/// println!("Done");
+/// ```
+pub fn clear_line() {
+    emit("\r\x1b[2K");
+}
+
+/// Clears the whole screen and moves the cursor to the top-left corner.
+pub fn clear_screen() {
+    emit("\x1b[2J\x1b[H");
+}
+
+/// Moves the cursor up by `n` lines, keeping its column.
+///
+/// Moving up by zero lines does nothing.
+///
+/// # Arguments
+///
+/// * `n` - The number of lines to move up.
+///
+/// # Examples
+///
+/// Redrawing a table in place:
+/// ```no_run
+/// use cliux::Table;
+/// use cliux::term::move_up;
+///
+/// let mut rendered = Table::new().headers(["Job", "State"]).row(["build", "running"]).render();
+/// println!("{}", rendered);
+///
+/// move_up(rendered.lines().count());
+/// rendered = Table::new().headers(["Job", "State"]).row(["build", "done"]).render();
+/// println!("{}", rendered);
+/// ```
+pub fn move_up(n: usize) {
+    if n > 0 {
+        emit(&format!("\x1b[{}A", n));
+    }
+}
+
+/// Hides the cursor until the returned guard is dropped.
+///
+/// The cursor is shown again when the guard goes out of scope, including
+/// when the thread unwinds from a panic, so it is never left hidden.
+///
+/// # Returns
+///
+/// A `CursorGuard` that shows the cursor again when dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use cliux::term::hide_cursor;
+///
+/// let _cursor = hide_cursor();
+/// // ... redraw a progress display ...
+/// // The cursor is shown again here, when `_cursor` is dropped
+/// ```
+pub fn hide_cursor() -> CursorGuard {
+    emit("\x1b[?25l");
+    CursorGuard { _private: () }
+}
+
+/// Shows the cursor.
+///
+/// Dropping a `CursorGuard` calls this, so it is only needed when the
+/// cursor was hidden by other means.
+pub fn show_cursor() {
+    emit("\x1b[?25h");
+}
+
+/// Keeps the cursor hidden until it is dropped.
+///
+/// Returned by `hide_cursor`.
+#[must_use = "dropping the guard shows the cursor again immediately"]
+pub struct CursorGuard {
+    _private: (),
+}
+
+impl Drop for CursorGuard {
+    fn drop(&mut self) {
+        show_cursor();
+    }
+}
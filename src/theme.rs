@@ -0,0 +1,125 @@
+//! A global theme that supplies the default look of every component.
+
+use std::sync::{LazyLock, RwLock};
+
+/// The default colors, borders, and glyphs used by components.
+///
+/// Components read the active theme when they are created, so one call to
+/// `set_theme` at startup changes the look of everything built afterwards.
+/// Builder methods such as `.style()`, `.color()`, and `.bullet()` still
+/// override the theme for a single component.
+///
+/// # Examples
+///
+/// Starting from a preset and changing one field:
+/// ```
+/// use cliux::Theme;
+///
+/// let theme = Theme {
+///     accent_color: Some("magenta".to_string()),
+///     ..Theme::dark()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// The border style for boxed components (`"rounded"`, `"square"`, or `"+"`).
+    /// `None` keeps each component's own default.
+    pub border_style: Option<String>,
+    /// The default color of prompts, `KeyValue` keys, and `ProgressBar` fills.
+    pub accent_color: Option<String>,
+    /// The default `List` bullet.
+    pub bullet: String,
+    /// The default character for `Section` and `Divider` rules.
+    pub divider: char,
+    /// The color of `"debug"` notes and alerts.
+    pub note_debug_color: String,
+    /// The color of `"info"` notes and alerts.
+    pub note_info_color: String,
+    /// The color of `"tip"` notes and alerts.
+    pub note_tip_color: String,
+    /// The color of `"warning"` notes and alerts.
+    pub note_warning_color: String,
+    /// The color of `"error"` notes and alerts.
+    pub note_error_color: String,
+}
+
+impl Default for Theme {
+    /// The theme matching each component's built-in defaults.
+    fn default() -> Self {
+        Self {
+            border_style: None,
+            accent_color: None,
+            bullet: "•".to_string(),
+            divider: '─',
+            note_debug_color: "cyan".to_string(),
+            note_info_color: "blue".to_string(),
+            note_tip_color: "green".to_string(),
+            note_warning_color: "yellow".to_string(),
+            note_error_color: "red".to_string(),
+        }
+    }
+}
+
+impl Theme {
+    /// A theme with rounded borders and softer colors suited to dark terminals.
+    ///
+    /// # Returns
+    ///
+    /// A new `Theme` instance.
+    pub fn dark() -> Self {
+        Self {
+            border_style: Some("rounded".to_string()),
+            accent_color: Some("cyan".to_string()),
+            note_debug_color: "110".to_string(),
+            note_info_color: "75".to_string(),
+            note_tip_color: "114".to_string(),
+            note_warning_color: "221".to_string(),
+            note_error_color: "203".to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// A theme that draws borders, bullets, and rules with ASCII characters only.
+    ///
+    /// Unlike `set_unicode(false)`, this only changes defaults, so glyphs set
+    /// on individual components and `Note` kind icons are kept.
+    ///
+    /// # Returns
+    ///
+    /// A new `Theme` instance.
+    pub fn ascii() -> Self {
+        Self {
+            border_style: Some("+".to_string()),
+            bullet: "*".to_string(),
+            divider: '-',
+            ..Self::default()
+        }
+    }
+}
+
+static THEME: LazyLock<RwLock<Theme>> = LazyLock::new(|| RwLock::new(Theme::default()));
+
+/// Sets the theme that newly created components take their defaults from.
+///
+/// # Arguments
+///
+/// * `theme` - The `Theme` to apply.
+///
+/// # Examples
+///
+/// ```standalone_crate
+/// use cliux::{List, Theme, set_theme};
+///
+/// set_theme(Theme::ascii());
+/// assert_eq!(List::new(vec!["One", "Two"]).render(), "* One\n* Two");
+/// ```
+pub fn set_theme(theme: Theme) {
+    if let Ok(mut current) = THEME.write() {
+        *current = theme;
+    }
+}
+
+/// Returns a copy of the active theme.
+pub fn theme() -> Theme {
+    THEME.read().map(|t| t.clone()).unwrap_or_default()
+}